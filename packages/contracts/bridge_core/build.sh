@@ -5,6 +5,10 @@ set -e
 CONTRACT_NAME="bridge_core"
 TARGET_DIR="target/wasm32-unknown-unknown/release"
 
+# 格式检查：未格式化的代码不应进入提交
+echo "Checking formatting..."
+cargo fmt --all -- --check
+
 echo "Building contract..."
 
 # 编译 WASM
@@ -1,29 +1,67 @@
 //! 合约业务逻辑与入口函数
 extern crate alloc;
 
-use alloc::{format, string::String, vec::Vec};
+use alloc::{boxed::Box, format, string::String, vec::Vec};
 use casper_contract::{
-    contract_api::{account, runtime, storage, system},
+    contract_api::{runtime, storage},
     unwrap_or_revert::UnwrapOrRevert,
 };
-use casper_types::{contracts::ContractHash, runtime_args, CLTyped, Key, U256, U512};
+use casper_types::{contracts::ContractHash, runtime_args, CLTyped, Key, U256};
 
 //TODO 需要重写资金/钱包相关逻辑
 
 use crate::{
+    adapter::{
+        adapter_for, bridge_package_key, key_to_contract_hash, AssetAdapter, Cep18Adapter,
+        NativeAdapter,
+    },
+    address::{is_supported_address_format, parse_address, parse_token_address},
     events::{
-        emit, CeETHBurned, CeETHMinted, CsprLockedForTarget, EventType, HotSwapActivated,
-        HotSwapProposed, PauseChanged, UnlockFinalized, UnlockRequested, YieldAccrued,
+        emit, emit_outbound, init_events, AdminTransferred, ApprovalTiersUpdated, AprUpdated,
+        AssetBurned, AssetLocked, AssetMinted, AssetRouteUpdated, AssetUpdated, BridgeInitialized,
+        CeETHBurned, CeETHMinted, CeETHTokenSet, ChainUpdated, CsprLockedForTarget, FeeBpsUpdated,
+        FeesWithdrawn, GuardianVoted, HotSwapActivated, HotSwapProposed, MaxRelayerTipBpsUpdated,
+        MintAuthorizationChanged, MintRequested, PauseChanged, PerformanceFeeBpsUpdated,
+        PositionWithdrawn, ProtocolReserveWithdrawn, RateLimitUpdated, RelayerBondConfigUpdated,
+        RelayerBondWithdrawn, RelayerRegistered, RelayerSlashed, RelayerUnbonding,
+        ReleaseCancelled, ReleaseDelayUpdated, ReleaseQueued, RequestConflict, RequestRejected,
+        ReserveWatermarkUpdated, RewardReserveFunded, RewardReserveLow, RouteFeeLimitsUpdated,
+        TreasurerChanged, UnlockFinalized, UnlockRequested, YieldAccrued,
     },
     storage::{
-        ensure_dictionaries, get_admin, get_ceeth_token, get_guardian_weight, is_paused,
-        is_tx_processed, mark_tx_processed, read_apr_bps, read_dictionary_value, read_threshold,
-        set_admin, set_ceeth_token, set_paused, write_active_patch, write_base_config,
-        write_dictionary_value, DICT_BALANCES, DICT_CEETH_MINT_REQS, DICT_HOTSWAP,
-        DICT_UNLOCK_REQS, KEY_ADMIN,
+        clear_pending_release, create_bridge_purse, ensure_dictionaries, get_admin,
+        get_guardian_weight, get_treasurer, is_inbound_processed, is_outbound_tx_used, is_paused,
+        mark_inbound_processed, mark_outbound_tx_used, read_approval_tiers, read_apr_bps,
+        read_asset, read_asset_ids, read_asset_route, read_ceeth_token, read_chain, read_chain_ids,
+        read_fee_bps, read_guardian_total_weight, read_max_relayer_tip_bps, read_min_relayer_bond,
+        read_mint_authorized, read_outbound_accumulator, read_pending_release,
+        read_performance_fee_bps, read_protocol_reserve, read_rate_limit, read_record,
+        read_relayer, read_relayer_unbonding_ms, read_release_delay, read_reserve_low_watermark,
+        read_reserves, read_reward_reserve, read_source_event_claim, read_threshold,
+        read_total_accrued_interest, read_total_principal, read_treasury_balance, record_vote,
+        save_guardians, set_admin, set_ceeth_token, set_paused, set_treasurer, write_active_patch,
+        write_approval_tiers, write_asset, write_asset_ids, write_asset_route, write_base_config,
+        write_chain, write_chain_ids, write_fee_bps, write_max_relayer_tip_bps,
+        write_min_relayer_bond, write_mint_authorized, write_pending_release,
+        write_performance_fee_bps, write_protocol_reserve, write_rate_limit, write_record,
+        write_relayer, write_relayer_unbonding_ms, write_release_delay,
+        write_reserve_low_watermark, write_reserves, write_reward_reserve,
+        write_source_event_claim, write_total_accrued_interest, write_total_principal,
+        write_treasury_balance, StorageKey, KEY_ADMIN,
+    },
+    types::{
+        ApprovalTier, AssetConfig, AssetReserves, AssetRoute, BridgeError, ChainConfig, Guardian,
+        HotSwapPatch, OutboundAccumulator, PendingRelease, RateLimit, RelayerInfo, ReleaseDelay,
+        SolvencyReport, UnlockRequest, VaultPosition, ASSET_CEETH, ASSET_CSPR,
+        ASSET_MODE_LOCK_UNLOCK, ASSET_MODE_MINT_BURN, CEETH_DECIMALS, CSPR_DECIMALS,
+        LOCAL_CHAIN_ID, RELEASE_KIND_MINT, RELEASE_KIND_UNLOCK, VOTE_KIND_HOT_SWAP, VOTE_KIND_MINT,
+        VOTE_KIND_REJECT, VOTE_KIND_SLASH, VOTE_KIND_UNLOCK,
+    },
+    utils::{
+        canonical_tx_hash, check_supply_invariants, compute_fee, compute_message_id,
+        compute_payload_digest, compute_yield, decay_rate_limit_usage, rate_limit_admits,
+        required_approval_weight, scale_amount, scale_amount_floor,
     },
-    types::{BridgeError, Guardian, HotSwapPatch, UnlockRequest, VaultPosition},
-    utils::compute_yield,
 };
 
 /// 确保调用者为管理员
@@ -40,6 +78,64 @@ fn ensure_admin() {
     }
 }
 
+/// 确保调用者为金库管理员（treasurer）
+fn ensure_treasurer() {
+    let caller = runtime::get_caller();
+    match get_treasurer() {
+        Key::Account(account_hash) if account_hash == caller => {}
+        _ => runtime::revert(BridgeError::PermissionDenied),
+    }
+}
+
+/// 确保调用者为已注册且处于激活状态的 Relayer
+fn ensure_registered_relayer() {
+    let caller = Key::Account(runtime::get_caller().into());
+    match read_relayer(&caller) {
+        Some(info) if info.active => {}
+        _ => runtime::revert(BridgeError::RelayerNotRegistered),
+    }
+}
+
+/// 校验链已注册且开放
+fn ensure_chain(chain: &str) -> ChainConfig {
+    let config = read_chain(chain).unwrap_or_revert_with(BridgeError::InvalidChain);
+    if !config.enabled {
+        runtime::revert(BridgeError::InvalidChain);
+    }
+    config
+}
+
+/// 入站请求的目标链必须是本链
+fn ensure_local_chain(chain: &str) {
+    if chain != LOCAL_CHAIN_ID {
+        runtime::revert(BridgeError::InvalidNetwork);
+    }
+}
+
+/// 校验资产已注册、已开放且桥接模式匹配
+fn ensure_asset(asset: &str, mode: u8) -> AssetConfig {
+    let config = read_asset(asset).unwrap_or_revert_with(BridgeError::InvalidAsset);
+    if !config.enabled || config.mode != mode {
+        runtime::revert(BridgeError::InvalidAsset);
+    }
+    config
+}
+
+/// 校验资产在指定链上有已开放的映射
+fn ensure_asset_route(asset: &str, chain: &str) -> AssetRoute {
+    match read_asset_route(asset, chain) {
+        Some(route) if route.enabled => route,
+        _ => runtime::revert(BridgeError::InvalidAsset),
+    }
+}
+
+/// 检查单笔金额（本链精度）是否在资产路由的上下限内
+fn ensure_route_amount(route: &AssetRoute, amount: U256) {
+    if amount < route.min_amount || (!route.max_amount.is_zero() && amount > route.max_amount) {
+        runtime::revert(BridgeError::InvalidAmount);
+    }
+}
+
 /// 检查是否暂停
 fn ensure_not_paused() {
     if is_paused() {
@@ -52,165 +148,779 @@ fn now_ms() -> u64 {
     runtime::get_blocktime().into()
 }
 
-/// 将 Key 转换为 ContractHash
-fn key_to_contract_hash(key: Key) -> Option<ContractHash> {
-    // 1. 模式匹配解包
-    if let Key::Hash(hash_addr) = key {
-        // 2. HashAddr 本质就是 [u8; 32]，ContractHash 也是 [u8; 32]
-        // 所以直接返回即可，或者显式转换
-        Some(ContractHash::new(hash_addr))
-    } else {
-        None // 如果这个 Key 不是 Hash 类型（比如是 Account 或 URef），则无法转换
+/// 校验 Bridge 可以铸造该资产，返回其适配器
+///
+/// 授权记录只能由 set_mint_authorized 在代币合约上实际验证 minter 权限后写入，
+/// 未授权时给出明确的 MintNotPermitted；代币侧之后撤销权限时铸造本身会 revert
+fn ensure_mint_permitted(asset: &AssetConfig) -> Box<dyn AssetAdapter> {
+    let adapter = adapter_for(asset);
+    if !read_mint_authorized(&asset.id) {
+        runtime::revert(BridgeError::MintNotPermitted);
+    }
+    adapter
+}
+
+/// 读取用户头寸，从未入账的账户视为空头寸
+fn read_position(account: &Key) -> VaultPosition {
+    read_record(StorageKey::Balance(account)).unwrap_or(VaultPosition {
+        principal: U256::zero(),
+        last_accrual_ms: now_ms(),
+    })
+}
+
+/// 计算头寸截至 current 的待计提利息，返回 (可支付利息, 业绩分成, 储备未覆盖的利息)
+fn pending_accrual(position: &VaultPosition, current: u64) -> (U256, U256, U256) {
+    if current < position.last_accrual_ms {
+        runtime::revert(BridgeError::InvalidTimestamp);
     }
+    let delta = current - position.last_accrual_ms;
+    let apr = read_apr_bps().unwrap_or_revert();
+    let interest = compute_yield(position.principal, apr, delta);
+
+    // 利息只能从已注资的储备中支付，不足部分不计提
+    let accrued = interest.min(read_reward_reserve());
+
+    // 协议按比例抽取业绩分成，剩余部分计入用户本金
+    let fee = compute_fee(
+        accrued,
+        read_performance_fee_bps(),
+        U256::zero(),
+        U256::zero(),
+    );
+    (accrued, fee, interest - accrued)
 }
 
-/// 读取并计提用户生息
+/// 读取并计提用户生息（消耗储备并写入全局记账，调用方须写回头寸）
 fn accrue_position(account: &Key) -> VaultPosition {
-    let key = account.to_formatted_string();
-    let mut position: VaultPosition =
-        read_dictionary_value(DICT_BALANCES, &key).unwrap_or(VaultPosition {
-            principal: U256::zero(),
-            last_accrual_ms: now_ms(),
+    let mut position = read_position(account);
+    let current = now_ms();
+    if current == position.last_accrual_ms {
+        return position;
+    }
+
+    let (accrued, fee, uncovered) = pending_accrual(&position, current);
+    let net_interest = accrued - fee;
+    if !accrued.is_zero() {
+        position.principal = position.principal.saturating_add(net_interest);
+        write_reward_reserve(read_reward_reserve() - accrued);
+        write_total_accrued_interest(read_total_accrued_interest().saturating_add(net_interest));
+        write_protocol_reserve(read_protocol_reserve().saturating_add(fee));
+    }
+    position.last_accrual_ms = current; //若没有这一行，则会导致利息无限累加
+
+    emit(YieldAccrued {
+        account: *account,
+        principal_after: position.principal,
+        gross_interest: accrued,
+        fee,
+        net_interest,
+    });
+
+    check_reserve_health(uncovered);
+    position
+}
+
+/// 储备低于告警水位或出现未覆盖利息时发出告警事件
+fn check_reserve_health(uncovered_interest: U256) {
+    let reserve = read_reward_reserve();
+    let watermark = read_reserve_low_watermark();
+    if !uncovered_interest.is_zero() || reserve < watermark {
+        emit(RewardReserveLow {
+            reserve,
+            watermark,
+            uncovered_interest,
         });
+    }
+}
 
-    let current = now_ms();
-    if current > position.last_accrual_ms {
-        let delta = current - position.last_accrual_ms;
-        let apr = read_apr_bps().unwrap_or_revert();
-        let interest = compute_yield(position.principal, apr, delta);
-        position.principal = position.principal.saturating_add(interest);
-        position.last_accrual_ms = current; //若没有这一行，则会导致利息无限累加
-
-        emit(YieldAccrued {
-            account: *account,
-            principal_after: position.principal,
-            event_type: EventType::YIELD_ACCRUED,
+/// 本金入账（同步全局本金总额）
+fn credit_principal(account: &Key, amount: U256) {
+    let mut pos = accrue_position(account);
+    pos.principal = pos.principal.saturating_add(amount);
+    save_position(account, pos);
+    write_total_principal(read_total_principal().saturating_add(amount));
+}
+
+/// 本金出账：先冲减全局本金，不足部分冲减已计提利息
+///
+/// 头寸内本金与利息已合并，全局两项之和始终等于所有头寸之和
+fn debit_principal(account: &Key, amount: U256) -> VaultPosition {
+    let mut pos = accrue_position(account);
+    if amount > pos.principal {
+        runtime::revert(BridgeError::InsufficientFunds);
+    }
+    pos.principal -= amount;
+    save_position(account, pos.clone());
+
+    let total_principal = read_total_principal();
+    let from_principal = amount.min(total_principal);
+    write_total_principal(total_principal - from_principal);
+    write_total_accrued_interest(
+        read_total_accrued_interest().saturating_sub(amount - from_principal),
+    );
+    pos
+}
+
+/// 按资产路由配置扣除协议手续费并记入金库，返回 (净额, 手续费)
+///
+/// unit 为净额需要对齐的最小单位：出站时手续费向上取整到目标链可表示的精度，保证净额没有 dust
+fn take_fee(asset: &str, chain: &str, amount: U256, unit: U256) -> (U256, U256) {
+    let mut fee = quote_fee(asset, chain, amount);
+    let remainder = fee % unit;
+    if !remainder.is_zero() {
+        fee = (fee + (unit - remainder)).min(amount);
+    }
+    let net = amount - fee;
+    if net.is_zero() {
+        runtime::revert(BridgeError::InvalidAmount);
+    }
+
+    book_fee(asset, fee);
+    (net, fee)
+}
+
+/// 按当前费率与资产路由上的手续费上下限计算手续费（本链精度）
+fn quote_fee(asset: &str, chain: &str, amount: U256) -> U256 {
+    let (min_fee, max_fee) = read_asset_route(asset, chain)
+        .map(|route| (route.min_fee, route.max_fee))
+        .unwrap_or_default();
+    compute_fee(amount, read_fee_bps(), min_fee, max_fee)
+}
+
+/// 手续费记入金库
+fn book_fee(asset: &str, fee: U256) {
+    if !fee.is_zero() {
+        write_treasury_balance(asset, read_treasury_balance(asset).saturating_add(fee));
+    }
+}
+
+/// 按 max_relayer_tip_bps 计算金额允许的最大小费
+fn max_relayer_tip(amount: U256) -> U256 {
+    amount.saturating_mul(U256::from(read_max_relayer_tip_bps())) / U256::from(10_000u32)
+}
+
+/// 出站小费不能超过管理员配置的上限
+fn ensure_tip_within_cap(amount: U256, relayer_tip: U256) {
+    if relayer_tip > max_relayer_tip(amount) {
+        runtime::revert(BridgeError::RelayerTipTooHigh);
+    }
+}
+
+/// 从净额中扣除 Relayer 小费，返回接收人实际到账金额
+fn deduct_relayer_tip(net_amount: U256, relayer_tip: U256) -> U256 {
+    if relayer_tip >= net_amount {
+        runtime::revert(BridgeError::InvalidAmount);
+    }
+    net_amount - relayer_tip
+}
+
+/// 入站请求创建时校验金额足以覆盖小费与当前手续费
+fn ensure_inbound_payable(asset: &str, chain: &str, amount: U256, relayer_tip: U256) {
+    if relayer_tip.saturating_add(quote_fee(asset, chain, amount)) >= amount {
+        runtime::revert(BridgeError::InvalidAmount);
+    }
+}
+
+/// 入站释放时扣除手续费与小费，返回 (到账金额, 手续费)
+///
+/// 手续费以扣除小费后的金额封顶：请求创建后管理员上调费率，释放也不会 revert 而使资金滞留
+fn settle_inbound(req: &UnlockRequest) -> (U256, U256) {
+    let after_tip = req.amount.saturating_sub(req.relayer_tip);
+    let fee = quote_fee(&req.asset, &req.src_chain, req.amount).min(after_tip);
+    book_fee(&req.asset, fee);
+    (after_tip - fee, fee)
+}
+
+/// 将本链金额换算为目标链精度，存在 dust 时 revert
+fn to_remote(config: &AssetConfig, route: &AssetRoute, amount: U256) -> U256 {
+    scale_amount(amount, config.decimals, route.remote_decimals).unwrap_or_revert()
+}
+
+/// 将源链金额换算为本链精度
+fn to_local(config: &AssetConfig, route: &AssetRoute, amount: U256) -> U256 {
+    // 源链资金已经锁定或销毁，本链无法表示的尾数舍去而不是拒绝，否则该笔资金永远无法释放
+    scale_amount_floor(amount, route.remote_decimals, config.decimals).unwrap_or_revert()
+}
+
+/// 本链金额中目标链可表示的最小单位
+fn dust_unit(config: &AssetConfig, route: &AssetRoute) -> U256 {
+    if config.decimals > route.remote_decimals {
+        to_local(config, route, U256::one())
+    } else {
+        U256::one()
+    }
+}
+
+/// 出站校验：资产与链路由已开放、目标地址合法、交易未处理
+///
+/// 返回 (资产配置, 远端映射, 规范化后的目标地址)
+fn prepare_outbound(
+    asset: &str,
+    mode: u8,
+    dst_chain: &str,
+    recipient: &str,
+    amount: U256,
+    tx_id: &str,
+) -> (AssetConfig, AssetRoute, Vec<u8>) {
+    if amount.is_zero() {
+        runtime::revert(BridgeError::InvalidAmount);
+    }
+    let config = ensure_asset(asset, mode);
+    let chain = ensure_chain(dst_chain);
+    let route = ensure_asset_route(asset, dst_chain);
+    ensure_route_amount(&route, amount);
+    // 目标链精度无法表示的金额直接拒绝，避免尾数滞留在 Bridge
+    to_remote(&config, &route, amount);
+    // 资金转移前校验目标地址，防止转入无法找回的地址
+    let recipient = parse_address(chain.address_format, recipient).unwrap_or_revert();
+    if is_outbound_tx_used(tx_id) {
+        runtime::revert(BridgeError::TxAlreadyProcessed);
+    }
+    (config, route, recipient)
+}
+
+/// 将资产锁入 Bridge 托管并扣除手续费与小费，返回 (接收人到账金额, 手续费, 实际小费)
+///
+/// 锁定的资产是目标链资产的储备，计入 total_locked，不再记为调用者的生息头寸
+fn lock_into_bridge(
+    asset: &AssetConfig,
+    route: &AssetRoute,
+    amount: U256,
+    relayer_tip: U256,
+) -> (U256, U256, U256) {
+    adapter_for(asset).pull(amount);
+
+    let unit = dust_unit(asset, route);
+    let (net_amount, fee) = take_fee(&asset.id, &route.chain, amount, unit);
+    // 小费在目标链完成时支付给投递的 Relayer，接收人到账金额为净额扣除小费
+    ensure_tip_within_cap(amount, relayer_tip);
+    // 目标链无法表示的小费尾数向下取整，留在接收人的到账金额中
+    let relayer_tip = relayer_tip - relayer_tip % unit;
+    let credited = deduct_relayer_tip(net_amount, relayer_tip);
+
+    // 手续费转入金库，不计入托管储备；小费与到账金额均由托管资产支撑
+    update_reserves(&asset.id, |r| {
+        r.total_locked = r.total_locked.saturating_add(net_amount)
+    });
+    (credited, fee, relayer_tip)
+}
+
+/// 检查同一 ID 下是否已有请求，返回 true 表示本次提交与已有请求一致、不应重复写入
+///
+/// 未被拒绝的请求以不同内容重新提交时 revert，抢先提交错误内容的 Relayer 无法覆盖原请求；
+/// 已被守护节点拒绝的请求可由不同内容覆盖（发出冲突事件记录被替换的摘要），
+/// 避免错误小费等抢跑提交使源链资金永久无法释放。
+fn is_existing_request(key: StorageKey, proposed: &UnlockRequest) -> bool {
+    let existing: UnlockRequest = match read_record(key) {
+        Some(existing) => existing,
+        None => return false,
+    };
+
+    let existing_digest = compute_payload_digest(&existing).unwrap_or_revert();
+    let proposed_digest = compute_payload_digest(proposed).unwrap_or_revert();
+    if !existing.rejected {
+        if existing_digest != proposed_digest {
+            runtime::revert(BridgeError::PayloadMismatch);
+        }
+        return true;
+    }
+    // 被拒绝的内容不能原样重新提交
+    if existing_digest == proposed_digest {
+        runtime::revert(BridgeError::InvalidRequest);
+    }
+    emit(RequestConflict {
+        request_id: proposed.id.clone(),
+        existing_digest,
+        proposed_digest,
+        submitter: proposed.submitter,
+    });
+    false
+}
+
+/// 占用源链事件：同一 (源链, 交易哈希, log_index) 只能对应一个未被拒绝的请求
+///
+/// 已被其他请求占用时 revert，换一个接收人或金额重放同一源链事件无法再次铸造或解锁
+fn claim_source_event(src_chain: &str, src_tx_hash: &str, log_index: u64, request_id: &str) {
+    match read_source_event_claim(src_chain, src_tx_hash, log_index) {
+        Some(claimed) if claimed != request_id => runtime::revert(BridgeError::TxAlreadyProcessed),
+        Some(_) => {}
+        None => {
+            write_source_event_claim(src_chain, src_tx_hash, log_index, Some(request_id.into()))
+        }
+    }
+}
+
+/// 读取待审批请求并校验守护节点提交的内容摘要
+fn read_request_for_approval(key: StorageKey, payload_digest: &str) -> UnlockRequest {
+    let req: UnlockRequest = read_record(key).unwrap_or_revert_with(BridgeError::InvalidRequest);
+
+    if req.finalized || req.rejected {
+        runtime::revert(BridgeError::InvalidRequest);
+    }
+    if compute_payload_digest(&req).unwrap_or_revert() != payload_digest {
+        runtime::revert(BridgeError::PayloadMismatch);
+    }
+    req
+}
+
+/// 调用者的守护节点权重，非守护节点 revert
+fn ensure_guardian() -> (Key, u32) {
+    let guardian = Key::Account(runtime::get_caller().into());
+    let weight = get_guardian_weight(&guardian).unwrap_or(0) as u32;
+    if weight == 0 {
+        runtime::revert(BridgeError::PermissionDenied);
+    }
+    (guardian, weight)
+}
+
+/// 读取待拒绝的请求：已排队的请求只能在延迟窗口内通过 cancel_release 取消
+fn read_request_for_rejection(key: StorageKey, payload_digest: &str) -> UnlockRequest {
+    let req = read_request_for_approval(key, payload_digest);
+    if read_pending_release(&req.id).is_some() {
+        runtime::revert(BridgeError::InvalidRequest);
+    }
+    req
+}
+
+/// 守护节点对请求投拒绝票，拒绝权重达到阈值时返回 true
+///
+/// 拒绝票按请求内容摘要记录，被拒绝后以正确内容重新提交的请求需重新投票。
+/// 达到阈值后记录提交者与被拒绝的内容，作为罚没该 Relayer 的依据，并释放对源链事件的占用。
+fn vote_reject(req: &mut UnlockRequest, guardian: Key, weight: u32) -> bool {
+    let payload_digest = compute_payload_digest(req).unwrap_or_revert();
+    if !record_vote(StorageKey::RejectVote(&payload_digest, &guardian)) {
+        return false;
+    }
+
+    req.rejections_weight = req.rejections_weight.saturating_add(weight);
+    let threshold = read_threshold().unwrap_or_revert() as u32;
+    emit(GuardianVoted {
+        subject: req.id.clone(),
+        kind: VOTE_KIND_REJECT,
+        guardian,
+        weight,
+        approvals_weight: req.rejections_weight,
+        required_weight: threshold,
+    });
+    if req.rejections_weight < threshold {
+        return false;
+    }
+
+    req.rejected = true;
+    write_source_event_claim(&req.src_chain, &req.src_tx_hash, req.log_index, None);
+    write_record(
+        StorageKey::RejectedSubmission(&req.id, &req.submitter),
+        payload_digest.clone(),
+    );
+    emit(RequestRejected {
+        request_id: req.id.clone(),
+        asset: req.asset.clone(),
+        payload_digest,
+        submitter: req.submitter,
+        rejections_weight: req.rejections_weight,
+    });
+    true
+}
+
+/// 完成解锁：扣除手续费，将资金释放给接收人并向 submitter 支付小费
+fn release_unlock(req: &mut UnlockRequest) {
+    req.finalized = true;
+    let asset = read_asset(&req.asset).unwrap_or_revert_with(BridgeError::InvalidAsset);
+    let adapter = adapter_for(&asset);
+    let (credited, fee) = settle_inbound(req);
+    if asset.token.is_none() {
+        // 计息并释放到目标账户
+        credit_principal(&req.recipient, credited);
+    } else {
+        adapter.push(req.recipient, credited);
+    }
+    adapter.push(req.submitter, req.relayer_tip);
+    update_reserves(&req.asset, |r| {
+        r.total_released = r.total_released.saturating_add(req.amount)
+    });
+    mark_inbound_processed(&req.id);
+    emit(UnlockFinalized {
+        request_id: req.id.clone(),
+        recipient: req.recipient,
+        asset: req.asset.clone(),
+        amount: credited,
+        fee,
+        relayer_tip: req.relayer_tip,
+        submitter: req.submitter,
+    });
+}
+
+/// 完成铸造：扣除手续费后为接收人铸造包装资产，小费铸造给 submitter
+fn release_mint(req: &mut UnlockRequest) {
+    let asset = read_asset(&req.asset).unwrap_or_revert_with(BridgeError::InvalidAsset);
+    let adapter = ensure_mint_permitted(&asset);
+    let (minted, fee) = settle_inbound(req);
+    // 手续费部分铸造给 Bridge 自身，由 treasurer 提取；小费铸造给 submitter
+    adapter.mint(req.recipient, minted);
+    adapter.mint(req.submitter, req.relayer_tip);
+    adapter.mint(bridge_package_key(), fee);
+    update_reserves(&req.asset, |r| {
+        r.total_minted = r.total_minted.saturating_add(req.amount)
+    });
+
+    req.finalized = true;
+    mark_inbound_processed(&req.id);
+    if req.asset == ASSET_CEETH {
+        emit(CeETHMinted {
+            recipient: req.recipient,
+            asset: req.asset.clone(),
+            amount: minted,
+            fee,
+            relayer_tip: req.relayer_tip,
+            submitter: req.submitter,
+            tx_id: req.id.clone(),
         });
     } else {
-        runtime::revert(BridgeError::InvalidTimestamp);
+        emit(AssetMinted {
+            asset: req.asset.clone(),
+            recipient: req.recipient,
+            amount: minted,
+            fee,
+            relayer_tip: req.relayer_tip,
+            submitter: req.submitter,
+            request_id: req.id.clone(),
+        });
     }
+}
 
-    position
+/// 排队请求对应的存储位置
+fn pending_request_key(pending: &PendingRelease) -> StorageKey<'_> {
+    match pending.kind {
+        RELEASE_KIND_UNLOCK => StorageKey::UnlockRequest(&pending.request_id),
+        _ => StorageKey::MintRequest(&pending.asset, &pending.request_id),
+    }
+}
+
+/// 按请求类型完成释放
+fn release(req: &mut UnlockRequest, kind: u8) {
+    match kind {
+        RELEASE_KIND_UNLOCK => release_unlock(req),
+        RELEASE_KIND_MINT => release_mint(req),
+        _ => runtime::revert(BridgeError::InvalidRequest),
+    }
+}
+
+/// 限流在当前时间的已用额度
+fn current_usage(limit: &RateLimit) -> U256 {
+    decay_rate_limit_usage(
+        limit.used,
+        limit.cap,
+        limit.window_ms,
+        now_ms().saturating_sub(limit.updated_at_ms),
+    )
+}
+
+/// 链/资产当前剩余的限流额度（未配置限流时为 U256::MAX）
+fn remaining_capacity(chain: &str, asset: &str) -> U256 {
+    let limit = read_rate_limit(chain, asset);
+    if limit.cap.is_zero() {
+        return U256::MAX;
+    }
+    limit.cap.saturating_sub(current_usage(&limit))
+}
+
+/// 尝试占用限流额度，额度不足时返回 false 且不修改状态
+fn try_consume_rate_limit(chain: &str, asset: &str, amount: U256) -> bool {
+    let mut limit = read_rate_limit(chain, asset);
+    if limit.cap.is_zero() {
+        return true;
+    }
+    let used = current_usage(&limit);
+    if !rate_limit_admits(used, limit.cap, amount) {
+        return false;
+    }
+    limit.used = used.saturating_add(amount);
+    limit.updated_at_ms = now_ms();
+    write_rate_limit(chain, asset, limit);
+    true
+}
+
+/// 单笔金额超过 cap 的请求只能在窗口额度完全恢复后释放，并占满整个窗口
+fn try_consume_full_window(chain: &str, asset: &str) -> bool {
+    let mut limit = read_rate_limit(chain, asset);
+    if !current_usage(&limit).is_zero() {
+        return false;
+    }
+    limit.used = limit.cap;
+    limit.updated_at_ms = now_ms();
+    write_rate_limit(chain, asset, limit);
+    true
+}
+
+/// 金额是否超过来源链/资产的限流 cap
+fn exceeds_rate_limit_cap(chain: &str, asset: &str, amount: U256) -> bool {
+    let cap = read_rate_limit(chain, asset).cap;
+    !cap.is_zero() && amount > cap
+}
+
+/// 达到审批阈值后释放请求；大额或超出限流时写入待释放队列，由 execute_release 稍后执行
+fn release_or_queue(req: &mut UnlockRequest, kind: u8) {
+    // 已在队列中的请求只能通过 execute_release 释放
+    if read_pending_release(&req.id).is_some() {
+        return;
+    }
+
+    if kind == RELEASE_KIND_MINT {
+        update_reserves(&req.asset, |r| {
+            r.attested_remote_locked = r.attested_remote_locked.saturating_add(req.amount)
+        });
+    }
+
+    // 大额转账先进入延迟队列，给守护节点留出取消窗口；
+    // 超过限流 cap 的请求至少延迟一个完整窗口
+    let delay = read_release_delay(&req.asset);
+    let oversized = exceeds_rate_limit_cap(&req.src_chain, &req.asset, req.amount);
+    let delayed = delay.delay_ms > 0 && req.amount > delay.amount_threshold;
+    if !oversized && !delayed && try_consume_rate_limit(&req.src_chain, &req.asset, req.amount) {
+        release(req, kind);
+        return;
+    }
+
+    let queued_at_ms = now_ms();
+    let release_after_ms = if oversized {
+        let window_ms = read_rate_limit(&req.src_chain, &req.asset).window_ms;
+        queued_at_ms.saturating_add(delay.delay_ms.max(window_ms))
+    } else if delayed {
+        queued_at_ms.saturating_add(delay.delay_ms)
+    } else {
+        queued_at_ms
+    };
+    write_pending_release(PendingRelease {
+        request_id: req.id.clone(),
+        kind,
+        asset: req.asset.clone(),
+        queued_at_ms,
+        release_after_ms,
+    });
+    emit(ReleaseQueued {
+        request_id: req.id.clone(),
+        src_chain: req.src_chain.clone(),
+        asset: req.asset.clone(),
+        amount: req.amount,
+        remaining_capacity: remaining_capacity(&req.src_chain, &req.asset),
+        release_after_ms,
+    });
+}
+
+/// 更新资产的供应量计数
+fn update_reserves(asset: &str, update: impl FnOnce(&mut AssetReserves)) {
+    let mut reserves = read_reserves(asset);
+    update(&mut reserves);
+    write_reserves(asset, reserves);
 }
 
 /// 写回用户头寸
 fn save_position(account: &Key, pos: VaultPosition) {
-    let key = account.to_formatted_string();
-    write_dictionary_value(DICT_BALANCES, &key, pos);
+    write_record(StorageKey::Balance(account), pos);
+}
+
+/// 档位要求的权重不能超过全部守护节点的权重之和，否则该档位的请求永远无法通过
+fn ensure_tiers_reachable(tiers: &[ApprovalTier], total_weight: u32) {
+    if tiers.iter().any(|tier| tier.required_weight > total_weight) {
+        runtime::revert(BridgeError::InvalidRequest);
+    }
+}
+
+/// 校验并保存守护节点
+///
+/// 守护节点不可重复且权重大于 0；基础阈值与各资产已配置的审批档位都必须能由全部守护权重达到
+fn set_guardians(guardians: Vec<Guardian>, threshold: u32) {
+    let mut total_weight: u32 = 0;
+    for (i, guardian) in guardians.iter().enumerate() {
+        if guardian.weight == 0 || guardians[..i].iter().any(|g| g.key == guardian.key) {
+            runtime::revert(BridgeError::InvalidRequest);
+        }
+        total_weight += guardian.weight as u32;
+    }
+    if threshold == 0 || threshold > total_weight {
+        runtime::revert(BridgeError::InvalidRequest);
+    }
+    for asset in read_asset_ids() {
+        ensure_tiers_reachable(&read_approval_tiers(&asset), total_weight);
+    }
+    save_guardians(guardians);
 }
 
 /// 初始化合约
 pub fn init(admin: Key, guardians: Vec<Guardian>, threshold: u32, base_apr_bps: u32) {
-    // 创建必要存储
+    // 只能在安装时调用一次，否则任何人都可以重设管理员与守护节点
+    if runtime::get_key(KEY_ADMIN).is_some() {
+        runtime::revert(BridgeError::PermissionDenied);
+    }
+    // 创建必要存储与 CES 事件存储
     ensure_dictionaries();
+    init_events();
 
     // 写入管理员、阈值、APR、暂停标志
     let admin_uref = storage::new_uref(admin);
     runtime::put_key(KEY_ADMIN, admin_uref.into());
     write_base_config(threshold, base_apr_bps, false);
+    create_bridge_purse();
+    set_treasurer(admin);
+    save_asset(AssetConfig {
+        id: ASSET_CSPR.into(),
+        token: None,
+        decimals: CSPR_DECIMALS,
+        mode: ASSET_MODE_LOCK_UNLOCK,
+        enabled: true,
+    });
 
     // 保存守护权重
-    crate::storage::save_guardians(guardians);
+    let guardian_count = guardians.len() as u32;
+    set_guardians(guardians, threshold);
+    emit(BridgeInitialized {
+        admin,
+        guardian_count,
+        threshold,
+        base_apr_bps,
+    });
 }
 
 /// 创建跨链解锁请求（由后台 Relayer 触发）
+///
+/// 请求 ID 由合约根据源链事件计算，同一笔源链存款只能对应一个请求
+#[allow(clippy::too_many_arguments)]
 pub fn create_unlock_request(
-    request_id: String,
+    src_chain: String,
+    src_tx_hash: String,
+    log_index: u64,
     recipient: Key,
     amount: U256,
-    src_chain: String,
+    asset: String,
     dst_chain: String,
+    relayer_tip: Option<U256>,
 ) {
     ensure_not_paused();
+    ensure_registered_relayer();
 
     if amount.is_zero() {
         runtime::revert(BridgeError::InvalidAmount);
     }
-
-    if is_tx_processed(&request_id) {
+    let config = ensure_asset(&asset, ASSET_MODE_LOCK_UNLOCK);
+    // 交易哈希统一存为规范形式，请求摘要与事件中的写法与 ID 一致
+    let src_tx_hash = canonical_tx_hash(&src_tx_hash).unwrap_or_revert();
+    let route = ensure_asset_route(&asset, &src_chain);
+    // 金额与小费按源链事件原样提交（源链精度），换算为本链精度后再校验
+    let remote_amount = amount;
+    let amount = to_local(&config, &route, remote_amount);
+    ensure_chain(&src_chain);
+    ensure_route_amount(&route, amount);
+    ensure_local_chain(&dst_chain);
+
+    // 源链资金已经锁定，小费超过上限时截断而不是 revert，超出部分归接收人
+    let relayer_tip =
+        to_local(&config, &route, relayer_tip.unwrap_or_default()).min(max_relayer_tip(amount));
+    ensure_inbound_payable(&asset, &src_chain, amount, relayer_tip);
+
+    let request_id = compute_message_id(
+        &src_chain,
+        &src_tx_hash,
+        log_index,
+        &recipient,
+        remote_amount,
+        &asset,
+    )
+    .unwrap_or_revert();
+    if is_inbound_processed(&request_id) {
         runtime::revert(BridgeError::TxAlreadyProcessed);
     }
+    claim_source_event(&src_chain, &src_tx_hash, log_index, &request_id);
 
+    let required_weight = required_approval_weight(
+        amount,
+        &read_approval_tiers(&asset),
+        read_threshold().unwrap_or_revert(),
+    );
+    let submitter = Key::Account(runtime::get_caller().into());
     let req = UnlockRequest {
         id: request_id.clone(),
         recipient,
         amount,
+        remote_amount,
+        asset,
         src_chain,
+        src_tx_hash,
+        log_index,
         dst_chain,
         timestamp_ms: now_ms(),
         finalized: false,
         approvals_weight: 0,
+        required_weight,
+        relayer_tip,
+        submitter,
+        rejections_weight: 0,
+        rejected: false,
     };
+    if is_existing_request(StorageKey::UnlockRequest(&request_id), &req) {
+        return;
+    }
 
-    write_dictionary_value(DICT_UNLOCK_REQS, &request_id, req.clone());
+    write_record(StorageKey::UnlockRequest(&request_id), req.clone());
     emit(UnlockRequested {
         request_id,
         recipient,
         amount,
+        remote_amount,
+        asset: req.asset.clone(),
         src_chain: req.src_chain.clone(),
+        src_tx_hash: req.src_tx_hash.clone(),
+        log_index,
         dst_chain: req.dst_chain.clone(),
-        event_type: EventType::UNLOCK_REQUESTED,
+        relayer_tip,
+        submitter,
+        required_weight,
     });
 }
 
 /// 守护节点审批解锁请求（权重累加）
-pub fn approve_unlock(request_id: String) {
+///
+/// payload_digest 为守护节点与源链核对后的请求摘要，与链上请求不一致时拒绝投票
+pub fn approve_unlock(request_id: String, payload_digest: String) {
     ensure_not_paused();
+    let (voter, weight) = ensure_guardian();
 
-    let caller = runtime::get_caller();
-
-    let admin = get_admin();
-    match admin {
-        Key::Account(account_hash) => {
-            if account_hash != caller {
-                runtime::revert(BridgeError::PermissionDenied);
-            }
-        }
-        _ => runtime::revert(BridgeError::PermissionDenied),
-    }
-
-    let weight = get_guardian_weight(&Key::Account(caller.into())).unwrap_or(0) as u32;
-    if weight == 0 {
-        runtime::revert(BridgeError::PermissionDenied);
-    }
+    let mut req =
+        read_request_for_approval(StorageKey::UnlockRequest(&request_id), &payload_digest);
 
-    // 防止重复投票
-    let vote_key = format!(
-        "unlock_vote:{}:{}",
-        request_id,
-        caller.to_formatted_string()
-    );
-    if is_tx_processed(&vote_key) {
+    // 防止重复投票；投票按请求内容记录，被拒绝后重新提交的请求需重新投票
+    if !record_vote(StorageKey::UnlockVote(&payload_digest, &voter)) {
         return;
     }
-    mark_tx_processed(&vote_key);
-
-    let mut req: UnlockRequest = read_dictionary_value(DICT_UNLOCK_REQS, &request_id)
-        .unwrap_or_revert_with(BridgeError::InvalidRequest);
-
-    if req.finalized {
-        runtime::revert(BridgeError::InvalidRequest);
-    }
 
     req.approvals_weight = req.approvals_weight.saturating_add(weight);
+    emit(GuardianVoted {
+        subject: request_id.clone(),
+        kind: VOTE_KIND_UNLOCK,
+        guardian: voter,
+        weight,
+        approvals_weight: req.approvals_weight,
+        required_weight: req.required_weight,
+    });
 
-    // 达到阈值则直接完成
-    if req.approvals_weight >= read_threshold().unwrap_or_revert() as u32 {
-        req.finalized = true;
-        // 计息并释放到目标账户
-        let mut pos = accrue_position(&req.recipient);
-        pos.principal = pos.principal.saturating_add(req.amount);
-        save_position(&req.recipient, pos);
-        mark_tx_processed(&request_id);
-        emit(UnlockFinalized {
-            request_id: req.id.clone(),
-            recipient: req.recipient,
-            amount: req.amount,
-            event_type: EventType::UNLOCK_FINALIZED,
-        });
+    // 达到阈值则直接完成；超出限流额度时转入待释放队列
+    if req.approvals_weight >= req.required_weight {
+        release_or_queue(&mut req, RELEASE_KIND_UNLOCK);
     }
 
-    write_dictionary_value(DICT_UNLOCK_REQS, &request_id, req);
+    write_record(StorageKey::UnlockRequest(&request_id), req);
+}
+
+/// 守护节点拒绝内容与源链不符的解锁请求
+///
+/// payload_digest 为被拒绝的链上请求摘要；拒绝权重达到阈值后请求作废，
+/// 提交该内容的 Relayer 可被罚没，源链事件可由正确内容重新提交
+pub fn reject_unlock(request_id: String, payload_digest: String) {
+    ensure_not_paused();
+    let (guardian, weight) = ensure_guardian();
+    let mut req =
+        read_request_for_rejection(StorageKey::UnlockRequest(&request_id), &payload_digest);
+    vote_reject(&mut req, guardian, weight);
+    write_record(StorageKey::UnlockRequest(&request_id), req);
 }
 
 /// 提案热修复补丁（Hot-Swap）
@@ -229,11 +939,10 @@ pub fn propose_hot_swap(patch_hash: String) {
         activated: false,
     };
 
-    write_dictionary_value(DICT_HOTSWAP, &patch_hash, patch);
+    write_record(StorageKey::HotSwapPatch(&patch_hash), patch);
     emit(HotSwapProposed {
         patch_hash,
         proposer: Key::Account(runtime::get_caller().into()),
-        event_type: EventType::HOT_SWAP_PROPOSED,
     });
 }
 
@@ -260,17 +969,12 @@ pub fn approve_hot_swap(patch_hash: String) {
     }
 
     // 防重复投票
-    let vote_key = format!(
-        "hotswap_vote:{}:{}",
-        patch_hash,
-        caller.to_formatted_string()
-    );
-    if is_tx_processed(&vote_key) {
+    let voter = Key::Account(caller.into());
+    if !record_vote(StorageKey::HotSwapVote(&patch_hash, &voter)) {
         return;
     }
-    mark_tx_processed(&vote_key);
 
-    let mut patch: HotSwapPatch = read_dictionary_value(DICT_HOTSWAP, &patch_hash)
+    let mut patch: HotSwapPatch = read_record(StorageKey::HotSwapPatch(&patch_hash))
         .unwrap_or_revert_with(BridgeError::InvalidRequest);
 
     if patch.activated {
@@ -278,8 +982,17 @@ pub fn approve_hot_swap(patch_hash: String) {
     }
 
     patch.approved_weight = patch.approved_weight.saturating_add(weight);
+    let threshold = read_threshold().unwrap_or_revert() as u32;
+    emit(GuardianVoted {
+        subject: patch_hash.clone(),
+        kind: VOTE_KIND_HOT_SWAP,
+        guardian: voter,
+        weight,
+        approvals_weight: patch.approved_weight,
+        required_weight: threshold,
+    });
 
-    if patch.approved_weight >= read_threshold().unwrap_or_revert() as u32 {
+    if patch.approved_weight >= threshold {
         patch.activated = true;
         write_active_patch(patch.patch_hash.clone());
 
@@ -288,11 +1001,10 @@ pub fn approve_hot_swap(patch_hash: String) {
 
         emit(HotSwapActivated {
             patch_hash: patch.patch_hash.clone(),
-            event_type: EventType::HOT_SWAP_ACTIVATED,
         });
     }
 
-    write_dictionary_value(DICT_HOTSWAP, &patch_hash, patch);
+    write_record(StorageKey::HotSwapPatch(&patch_hash), patch);
 }
 
 /// 执行合约升级：通过反向授权，让目标 Upgrader 合约执行 add_contract_version
@@ -326,209 +1038,1142 @@ fn perform_upgrade(upgrader_contract_hash_str: String) {
 pub fn set_pause(paused: bool) {
     ensure_admin();
     set_paused(paused);
-    emit(PauseChanged {
-        paused,
-        event_type: EventType::PAUSE_CHANGED,
-    });
+    emit(PauseChanged { paused });
 }
 
 /// 更新基础 APR
 pub fn update_apr(new_apr_bps: u32) {
     ensure_admin();
     let threshold = read_threshold();
+    let old_apr_bps = read_apr_bps().unwrap_or_default();
     write_base_config(threshold.unwrap_or_revert(), new_apr_bps, is_paused());
+    emit(AprUpdated {
+        old_apr_bps,
+        new_apr_bps,
+    });
 }
 
 /// 迁移管理员
 pub fn transfer_admin(new_admin: Key) {
     ensure_admin();
+    let old_admin = get_admin();
     set_admin(new_admin);
+    emit(AdminTransferred {
+        old_admin,
+        new_admin,
+    });
 }
 
-/// 读取某账户的当前本金（含利息）
+/// 读取某账户的当前本金（含待计提利息的预估，只读，不写状态）
 pub fn get_position(account: Key) -> VaultPosition {
-    accrue_position(&account)
+    let mut pos = read_position(&account);
+    let current = now_ms();
+    let (accrued, fee, _) = pending_accrual(&pos, current);
+    pos.principal = pos.principal.saturating_add(accrued - fee);
+    pos.last_accrual_ms = current;
+    pos
 }
 
-// =========================
-// 新增：跨链 ceETH / wCSPR
-// =========================
+/// 为调用者的头寸计提利息并写回
+pub fn accrue() {
+    ensure_not_paused();
+    let account = Key::Account(runtime::get_caller().into());
+    let pos = accrue_position(&account);
+    save_position(&account, pos);
+}
 
-/// 设置 ceETH 合约哈希（仅管理员）
-pub fn set_ceeth_token_entry(token: Key) {
-    ensure_admin();
-    set_ceeth_token(token);
+/// 从调用者的头寸中提取 CSPR（本金与已计提利息）
+pub fn withdraw_position(amount: U256) {
+    ensure_not_paused();
+    if amount.is_zero() {
+        runtime::revert(BridgeError::InvalidAmount);
+    }
+    let account = Key::Account(runtime::get_caller().into());
+    let pos = debit_principal(&account, amount);
+    NativeAdapter::new().push(account, amount);
+    emit(PositionWithdrawn {
+        account,
+        amount,
+        principal_after: pos.principal,
+    });
 }
 
-/// CSPR -> ETH：锁仓 CSPR（计息资产记账），等待对端 mint wCSPR
-pub fn lock_cspr_for_eth(amount: U256, tx_id: String, dst_chain: String, recipient: String) {
+/// 向利息储备注资（任何人均可注资）
+pub fn fund_reward_reserve(amount: U256) {
     ensure_not_paused();
     if amount.is_zero() {
         runtime::revert(BridgeError::InvalidAmount);
     }
-    if is_tx_processed(&tx_id) {
-        runtime::revert(BridgeError::TxAlreadyProcessed);
+
+    NativeAdapter::new().pull(amount);
+
+    let reserve_after = read_reward_reserve().saturating_add(amount);
+    write_reward_reserve(reserve_after);
+    emit(RewardReserveFunded {
+        funder: Key::Account(runtime::get_caller().into()),
+        amount,
+        reserve_after,
+    });
+}
+
+/// 设置储备告警水位（仅管理员）
+pub fn set_reserve_low_watermark(amount: U256) {
+    ensure_admin();
+    let old_watermark = read_reserve_low_watermark();
+    write_reserve_low_watermark(amount);
+    emit(ReserveWatermarkUpdated {
+        old_watermark,
+        new_watermark: amount,
+    });
+    check_reserve_health(U256::zero());
+}
+
+/// 偿付能力视图：全局负债、储备与 purse 实际余额
+pub fn get_solvency() -> SolvencyReport {
+    let total_principal = read_total_principal();
+    let total_accrued_interest = read_total_accrued_interest();
+    let reward_reserve = read_reward_reserve();
+    let protocol_fees = read_treasury_balance(ASSET_CSPR);
+    let protocol_reserve = read_protocol_reserve();
+    let total_liabilities = total_principal.saturating_add(total_accrued_interest);
+    let reserves = read_reserves(ASSET_CSPR);
+    let locked_backing = reserves
+        .total_locked
+        .saturating_sub(reserves.total_released);
+    let purse_balance = NativeAdapter::new().balance_of(bridge_package_key());
+
+    SolvencyReport {
+        total_principal,
+        total_accrued_interest,
+        reward_reserve,
+        protocol_fees,
+        protocol_reserve,
+        total_liabilities,
+        locked_backing,
+        purse_balance,
+        solvent: purse_balance
+            >= total_liabilities
+                .saturating_add(locked_backing)
+                .saturating_add(reward_reserve)
+                .saturating_add(protocol_fees)
+                .saturating_add(protocol_reserve),
     }
+}
 
-    let caller_purse = account::get_main_purse();
+// =========================
+// 协议手续费与金库
+// =========================
 
-    // you need purse to transfer CSPR to bridge contract
-    let contract_main_purse = account::get_main_purse();
+/// 设置跨链手续费率（仅管理员）
+pub fn set_fee_bps(fee_bps: u32) {
+    ensure_admin();
+    if fee_bps > 10_000 {
+        runtime::revert(BridgeError::InvalidFee);
+    }
+    let old_fee_bps = read_fee_bps();
+    write_fee_bps(fee_bps);
+    emit(FeeBpsUpdated {
+        old_fee_bps,
+        new_fee_bps: fee_bps,
+    });
+}
 
-    system::transfer_from_purse_to_purse(
-        caller_purse,
-        contract_main_purse,
-        U512::from(amount.as_u128()),
-        None,
-    )
-    .unwrap_or_revert_with(BridgeError::TransferFailed);
+/// 设置利息业绩分成比例（仅管理员）
+pub fn set_performance_fee_bps(fee_bps: u32) {
+    ensure_admin();
+    if fee_bps > 10_000 {
+        runtime::revert(BridgeError::InvalidFee);
+    }
+    let old_fee_bps = read_performance_fee_bps();
+    write_performance_fee_bps(fee_bps);
+    emit(PerformanceFeeBpsUpdated {
+        old_fee_bps,
+        new_fee_bps: fee_bps,
+    });
+}
 
-    let caller = runtime::get_caller();
-    let mut pos = accrue_position(&Key::Account(caller.into()));
-    pos.principal = pos.principal.saturating_add(amount);
-    save_position(&Key::Account(caller.into()), pos);
+/// 设置 Relayer 小费上限，按金额的 bps 计（仅管理员，0 表示不允许小费）
+///
+/// 出站小费超过上限时 revert；入站请求的小费截断到上限，源链合约应配置相同的上限
+pub fn set_max_relayer_tip_bps(tip_bps: u32) {
+    ensure_admin();
+    if tip_bps > 10_000 {
+        runtime::revert(BridgeError::InvalidFee);
+    }
+    let old_tip_bps = read_max_relayer_tip_bps();
+    write_max_relayer_tip_bps(tip_bps);
+    emit(MaxRelayerTipBpsUpdated {
+        old_tip_bps,
+        new_tip_bps: tip_bps,
+    });
+}
+
+/// 设置资产在某条链上的手续费上下限（仅管理员，本链精度，max_fee 为 0 表示不设上限）
+pub fn set_route_fee_limits(asset: String, chain: String, min_fee: U256, max_fee: U256) {
+    ensure_admin();
+    if !max_fee.is_zero() && min_fee > max_fee {
+        runtime::revert(BridgeError::InvalidFee);
+    }
+    // 手续费上下限只存于资产路由中，路由须先配置
+    let mut route =
+        read_asset_route(&asset, &chain).unwrap_or_revert_with(BridgeError::InvalidAsset);
+    let (old_min_fee, old_max_fee) = (route.min_fee, route.max_fee);
+    route.min_fee = min_fee;
+    route.max_fee = max_fee;
+    write_asset_route(route);
+    emit(RouteFeeLimitsUpdated {
+        asset,
+        chain,
+        old_min_fee,
+        old_max_fee,
+        new_min_fee: min_fee,
+        new_max_fee: max_fee,
+    });
+}
+
+/// 更换金库管理员（仅管理员）
+pub fn set_treasurer_entry(treasurer: Key) {
+    ensure_admin();
+    let old_treasurer = get_treasurer();
+    set_treasurer(treasurer);
+    emit(TreasurerChanged {
+        old_treasurer,
+        new_treasurer: treasurer,
+    });
+}
+
+/// 提取金库中累计的手续费（仅 treasurer）
+pub fn withdraw_fees(asset: String, amount: U256, recipient: Key) {
+    ensure_treasurer();
+    if amount.is_zero() {
+        runtime::revert(BridgeError::InvalidAmount);
+    }
+    let config = read_asset(&asset).unwrap_or_revert_with(BridgeError::InvalidAsset);
+
+    let balance = read_treasury_balance(&asset);
+    if amount > balance {
+        runtime::revert(BridgeError::InsufficientFunds);
+    }
+    write_treasury_balance(&asset, balance - amount);
+
+    adapter_for(&config).push(recipient, amount);
+
+    emit(FeesWithdrawn {
+        asset,
+        recipient,
+        amount,
+    });
+}
+
+/// 查询金库中某资产的累计手续费
+pub fn get_treasury_balance(asset: String) -> U256 {
+    read_treasury_balance(&asset)
+}
+
+/// 提取协议储备中累计的利息业绩分成（仅 treasurer，以 CSPR 支付）
+pub fn withdraw_protocol_reserve(amount: U256, recipient: Key) {
+    ensure_treasurer();
+    if amount.is_zero() {
+        runtime::revert(BridgeError::InvalidAmount);
+    }
+    let reserve = read_protocol_reserve();
+    if amount > reserve {
+        runtime::revert(BridgeError::InsufficientFunds);
+    }
+    let reserve_after = reserve - amount;
+    write_protocol_reserve(reserve_after);
+
+    NativeAdapter::new().push(recipient, amount);
+
+    emit(ProtocolReserveWithdrawn {
+        recipient,
+        amount,
+        reserve_after,
+    });
+}
+
+// =========================
+// Relayer 注册表与保证金
+// =========================
+
+/// 设置 Relayer 最低保证金与退出等待期（仅管理员）
+pub fn set_relayer_bond_config(min_bond: U256, unbonding_ms: u64) {
+    ensure_admin();
+    let old_min_bond = read_min_relayer_bond();
+    let old_unbonding_ms = read_relayer_unbonding_ms();
+    write_min_relayer_bond(min_bond);
+    write_relayer_unbonding_ms(unbonding_ms);
+    emit(RelayerBondConfigUpdated {
+        old_min_bond,
+        new_min_bond: min_bond,
+        old_unbonding_ms,
+        new_unbonding_ms: unbonding_ms,
+    });
+}
+
+/// 质押 CSPR 注册为 Relayer；已注册时追加保证金
+pub fn register_relayer(bond: U256) {
+    ensure_not_paused();
+    if bond.is_zero() {
+        runtime::revert(BridgeError::InvalidAmount);
+    }
+
+    let relayer = Key::Account(runtime::get_caller().into());
+    let mut info = read_relayer(&relayer).unwrap_or(RelayerInfo {
+        bond: U256::zero(),
+        active: false,
+        registered_at_ms: now_ms(),
+        unbonding_at_ms: 0,
+    });
+    if info.unbonding_at_ms != 0 {
+        // 退出流程中不可重新激活，需先取回保证金
+        runtime::revert(BridgeError::BondLocked);
+    }
+
+    let total_bond = info.bond.saturating_add(bond);
+    if total_bond < read_min_relayer_bond() {
+        runtime::revert(BridgeError::InsufficientFunds);
+    }
+
+    NativeAdapter::new().pull(bond);
+
+    info.bond = total_bond;
+    info.active = true;
+    write_relayer(&relayer, info);
+    emit(RelayerRegistered {
+        relayer,
+        bond: total_bond,
+    });
+}
+
+/// 申请退出：立即停止创建请求的权限，保证金在等待期后才可取回（期间仍可被罚没）
+pub fn deregister_relayer() {
+    let relayer = Key::Account(runtime::get_caller().into());
+    let mut info = read_relayer(&relayer).unwrap_or_revert_with(BridgeError::RelayerNotRegistered);
+    if info.unbonding_at_ms != 0 {
+        runtime::revert(BridgeError::BondLocked);
+    }
+
+    info.active = false;
+    info.unbonding_at_ms = now_ms().saturating_add(read_relayer_unbonding_ms());
+    write_relayer(&relayer, info.clone());
+    emit(RelayerUnbonding {
+        relayer,
+        bond: info.bond,
+        unbonding_at_ms: info.unbonding_at_ms,
+    });
+}
+
+/// 等待期结束后取回保证金
+pub fn withdraw_relayer_bond() {
+    let relayer = Key::Account(runtime::get_caller().into());
+    let mut info = read_relayer(&relayer).unwrap_or_revert_with(BridgeError::RelayerNotRegistered);
+    if info.unbonding_at_ms == 0 || now_ms() < info.unbonding_at_ms {
+        runtime::revert(BridgeError::BondLocked);
+    }
+    if info.bond.is_zero() {
+        runtime::revert(BridgeError::InsufficientFunds);
+    }
+
+    let amount = info.bond;
+    info.bond = U256::zero();
+    info.unbonding_at_ms = 0;
+    write_relayer(&relayer, info);
+
+    NativeAdapter::new().push(relayer, amount);
+    emit(RelayerBondWithdrawn { relayer, amount });
+}
+
+/// 守护节点投票罚没提交伪造/被拒绝请求的 Relayer；达到阈值后保证金转入协议金库
+pub fn slash_relayer(relayer: Key, request_id: String) {
+    let (voter, weight) = ensure_guardian();
+
+    let mut info = read_relayer(&relayer).unwrap_or_revert_with(BridgeError::RelayerNotRegistered);
+    // 只能罚没提交过被守护节点拒绝（或取消）请求的 Relayer
+    if read_record::<String>(StorageKey::RejectedSubmission(&request_id, &relayer)).is_none() {
+        runtime::revert(BridgeError::InvalidRequest);
+    }
+
+    // 防重复投票
+    if !record_vote(StorageKey::RelayerSlashVote(&relayer, &request_id, &voter)) {
+        return;
+    }
+
+    let slash_key = StorageKey::RelayerSlash(&relayer, &request_id);
+    let approved_weight = read_record::<u32>(slash_key)
+        .unwrap_or(0)
+        .saturating_add(weight);
+    write_record(slash_key, approved_weight);
+    let threshold = read_threshold().unwrap_or_revert() as u32;
+    emit(GuardianVoted {
+        subject: format!("{}/{}", relayer.to_formatted_string(), request_id),
+        kind: VOTE_KIND_SLASH,
+        guardian: voter,
+        weight,
+        approvals_weight: approved_weight,
+        required_weight: threshold,
+    });
+
+    if approved_weight >= threshold && !info.bond.is_zero() {
+        let amount = info.bond;
+        info.bond = U256::zero();
+        info.active = false;
+        write_relayer(&relayer, info);
+        write_treasury_balance(
+            ASSET_CSPR,
+            read_treasury_balance(ASSET_CSPR).saturating_add(amount),
+        );
+        emit(RelayerSlashed {
+            relayer,
+            request_id,
+            amount,
+        });
+    }
+}
+
+/// 查询 Relayer 注册信息
+pub fn get_relayer(relayer: Key) -> RelayerInfo {
+    read_relayer(&relayer).unwrap_or_revert_with(BridgeError::RelayerNotRegistered)
+}
+
+// =========================
+// 链注册表
+// =========================
+
+/// 注册或更新一条远端链（仅管理员）
+///
+/// 单笔金额与手续费上下限按资产配置在资产路由上
+pub fn set_chain(id: String, evm_chain_id: u64, address_format: u8, decimals: u8, enabled: bool) {
+    ensure_admin();
+    if id.is_empty() || id == LOCAL_CHAIN_ID {
+        runtime::revert(BridgeError::InvalidChain);
+    }
+    if !is_supported_address_format(address_format) {
+        runtime::revert(BridgeError::InvalidAddress);
+    }
+
+    let mut ids = read_chain_ids();
+    if !ids.contains(&id) {
+        ids.push(id.clone());
+        write_chain_ids(ids);
+    }
+
+    let old = read_chain(&id);
+    let config = ChainConfig {
+        id: id.clone(),
+        evm_chain_id,
+        address_format,
+        decimals,
+        enabled,
+    };
+    write_chain(config.clone());
+    emit(ChainUpdated {
+        id,
+        old,
+        new: config,
+    });
+}
+
+/// 查询单条链配置
+pub fn get_chain(id: String) -> ChainConfig {
+    read_chain(&id).unwrap_or_revert_with(BridgeError::InvalidChain)
+}
+
+/// 查询当前开放的链（前端据此展示可用路由）
+pub fn get_supported_chains() -> Vec<String> {
+    read_chain_ids()
+        .into_iter()
+        .filter(|id| read_chain(id).map(|c| c.enabled).unwrap_or(false))
+        .collect()
+}
+
+// =========================
+// 入站释放限流
+// =========================
+
+/// 设置来源链/资产的入站释放限流（仅管理员），cap 为 0 表示不限流
+///
+/// 限制的是入站请求的解锁与铸造；单笔金额超过 cap 的请求进入队列，
+/// 至少延迟一个窗口，且只能在窗口额度完全恢复时由 execute_release 释放
+pub fn set_rate_limit(chain: String, asset: String, cap: U256, window_ms: u64) {
+    ensure_admin();
+    if !cap.is_zero() && window_ms == 0 {
+        runtime::revert(BridgeError::InvalidRequest);
+    }
+
+    // 保留已用额度，避免调整配置时重置窗口
+    let limit = read_rate_limit(&chain, &asset);
+    let used = current_usage(&limit);
+    write_rate_limit(
+        &chain,
+        &asset,
+        RateLimit {
+            cap,
+            window_ms,
+            used,
+            updated_at_ms: now_ms(),
+        },
+    );
+    emit(RateLimitUpdated {
+        chain,
+        asset,
+        old_cap: limit.cap,
+        new_cap: cap,
+        old_window_ms: limit.window_ms,
+        new_window_ms: window_ms,
+    });
+}
+
+/// 查询限流配置与状态
+pub fn get_rate_limit(chain: String, asset: String) -> RateLimit {
+    read_rate_limit(&chain, &asset)
+}
+
+/// 查询链/资产当前剩余的限流额度
+pub fn get_remaining_capacity(chain: String, asset: String) -> U256 {
+    remaining_capacity(&chain, &asset)
+}
+
+/// 释放排队中的请求（任何人可调用）
+///
+/// 请求不在队列中（已执行或已取消）、延迟期未结束或限流额度仍不足时 revert
+pub fn execute_release(request_id: String) {
+    ensure_not_paused();
+
+    let pending =
+        read_pending_release(&request_id).unwrap_or_revert_with(BridgeError::InvalidRequest);
+    if now_ms() < pending.release_after_ms {
+        runtime::revert(BridgeError::ReleaseNotReady);
+    }
+    let key = pending_request_key(&pending);
+    let mut req: UnlockRequest =
+        read_record(key).unwrap_or_revert_with(BridgeError::InvalidRequest);
+    if req.finalized {
+        runtime::revert(BridgeError::InvalidRequest);
+    }
+
+    let admitted = if exceeds_rate_limit_cap(&req.src_chain, &req.asset, req.amount) {
+        try_consume_full_window(&req.src_chain, &req.asset)
+    } else {
+        try_consume_rate_limit(&req.src_chain, &req.asset, req.amount)
+    };
+    if !admitted {
+        runtime::revert(BridgeError::RateLimitExceeded);
+    }
+    release(&mut req, pending.kind);
+    write_record(key, req);
+    clear_pending_release(&request_id);
+}
+
+/// 守护节点投票取消排队中的请求
+///
+/// 只能在延迟期结束前投票；拒绝权重达到阈值时请求被标记为已拒绝并移出队列，
+/// 与 reject_unlock/reject_mint 一样记录被拒绝的提交，之后可以正确内容重新提交
+pub fn cancel_release(request_id: String) {
+    let (guardian, weight) = ensure_guardian();
+
+    let pending =
+        read_pending_release(&request_id).unwrap_or_revert_with(BridgeError::InvalidRequest);
+    if now_ms() >= pending.release_after_ms {
+        runtime::revert(BridgeError::CancelWindowClosed);
+    }
+    let key = pending_request_key(&pending);
+    let mut req: UnlockRequest =
+        read_record(key).unwrap_or_revert_with(BridgeError::InvalidRequest);
+    if req.finalized || req.rejected {
+        runtime::revert(BridgeError::InvalidRequest);
+    }
+
+    if vote_reject(&mut req, guardian, weight) {
+        clear_pending_release(&request_id);
+        // 排队时已计入的远端锁仓确认随取消一并撤回
+        if pending.kind == RELEASE_KIND_MINT {
+            update_reserves(&req.asset, |r| {
+                r.attested_remote_locked = r.attested_remote_locked.saturating_sub(req.amount)
+            });
+        }
+        emit(ReleaseCancelled {
+            request_id,
+            guardian,
+        });
+    }
+    write_record(key, req);
+}
+
+/// 查询排队中的请求
+pub fn get_pending_release(request_id: String) -> PendingRelease {
+    read_pending_release(&request_id).unwrap_or_revert_with(BridgeError::InvalidRequest)
+}
+
+/// 设置资产的大额延迟释放（仅管理员），delay_ms 为 0 表示关闭
+pub fn set_release_delay(asset: String, amount_threshold: U256, delay_ms: u64) {
+    ensure_admin();
+    if read_asset(&asset).is_none() {
+        runtime::revert(BridgeError::InvalidAsset);
+    }
+    let old = read_release_delay(&asset);
+    write_release_delay(
+        &asset,
+        ReleaseDelay {
+            amount_threshold,
+            delay_ms,
+        },
+    );
+    emit(ReleaseDelayUpdated {
+        asset,
+        old_amount_threshold: old.amount_threshold,
+        new_amount_threshold: amount_threshold,
+        old_delay_ms: old.delay_ms,
+        new_delay_ms: delay_ms,
+    });
+}
+
+/// 查询资产的大额延迟释放配置
+pub fn get_release_delay(asset: String) -> ReleaseDelay {
+    read_release_delay(&asset)
+}
+
+// =========================
+// 审批档位
+// =========================
+
+/// 设置资产的金额审批档位（仅管理员）
+///
+/// 档位须按 max_amount 严格升序、required_weight 不递减且不超过全部守护节点的权重之和；
+/// 空列表表示只使用基础阈值
+pub fn set_approval_tiers(asset: String, tiers: Vec<ApprovalTier>) {
+    ensure_admin();
+    if read_asset(&asset).is_none() {
+        runtime::revert(BridgeError::InvalidAsset);
+    }
+    ensure_tiers_reachable(&tiers, read_guardian_total_weight());
+    for (i, tier) in tiers.iter().enumerate() {
+        if tier.required_weight == 0 {
+            runtime::revert(BridgeError::InvalidRequest);
+        }
+        if i > 0 {
+            let prev = &tiers[i - 1];
+            if tier.max_amount <= prev.max_amount || tier.required_weight < prev.required_weight {
+                runtime::revert(BridgeError::InvalidRequest);
+            }
+        }
+    }
+    let old = read_approval_tiers(&asset);
+    write_approval_tiers(&asset, tiers.clone());
+    emit(ApprovalTiersUpdated {
+        asset,
+        old,
+        new: tiers,
+    });
+}
+
+/// 查询资产的金额审批档位
+pub fn get_approval_tiers(asset: String) -> Vec<ApprovalTier> {
+    read_approval_tiers(&asset)
+}
+
+// =========================
+// 资产注册表
+// =========================
+
+/// 写入资产配置并维护资产 ID 列表
+fn save_asset(config: AssetConfig) {
+    let mut ids = read_asset_ids();
+    if !ids.contains(&config.id) {
+        ids.push(config.id.clone());
+        write_asset_ids(ids);
+    }
+    let old = read_asset(&config.id);
+    write_asset(config.clone());
+    emit(AssetUpdated {
+        id: config.id.clone(),
+        old,
+        new: config,
+    });
+}
+
+/// 精度以 CEP-18 合约自身的 decimals 为准，与管理员提供的不一致时 revert InvalidAsset
+fn ensure_token_decimals(token: Key, expected: u8) {
+    let token = key_to_contract_hash(token).unwrap_or_revert_with(BridgeError::InvalidAsset);
+    if Cep18Adapter::new(token).decimals() != expected {
+        runtime::revert(BridgeError::InvalidAsset);
+    }
+}
+
+/// 注册或更新资产（仅管理员）
+///
+/// token 为 None 表示原生 CSPR，只能以 lock/unlock 模式桥接；
+/// 其余资产必须指向 CEP-18 合约，mint/burn 模式要求 Bridge 拥有该代币的铸造权限
+pub fn register_asset(id: String, token: Option<Key>, decimals: u8, mode: u8, enabled: bool) {
+    ensure_admin();
+    let valid = match token {
+        None => id == ASSET_CSPR && decimals == CSPR_DECIMALS && mode == ASSET_MODE_LOCK_UNLOCK,
+        Some(token) => {
+            id != ASSET_CSPR
+                && !id.is_empty()
+                && key_to_contract_hash(token).is_some()
+                && (mode == ASSET_MODE_LOCK_UNLOCK || mode == ASSET_MODE_MINT_BURN)
+        }
+    };
+    if !valid {
+        runtime::revert(BridgeError::InvalidAsset);
+    }
+    if let Some(token) = token {
+        ensure_token_decimals(token, decimals);
+    }
+
+    save_asset(AssetConfig {
+        id,
+        token,
+        decimals,
+        mode,
+        enabled,
+    });
+}
+
+/// 设置资产在远端链上的代币地址与单笔金额上下限（仅管理员）
+///
+/// 远端为原生币（如 ETH）时使用零地址；remote_decimals 为远端代币精度，用于金额换算；
+/// min_amount / max_amount 按资产的本链精度计，max_amount 为 0 表示不设上限
+#[allow(clippy::too_many_arguments)]
+pub fn set_asset_route(
+    asset: String,
+    chain: String,
+    remote_token: String,
+    remote_decimals: u8,
+    enabled: bool,
+    min_amount: U256,
+    max_amount: U256,
+) {
+    ensure_admin();
+    if read_asset(&asset).is_none() {
+        runtime::revert(BridgeError::InvalidAsset);
+    }
+    if !max_amount.is_zero() && min_amount > max_amount {
+        runtime::revert(BridgeError::InvalidAmount);
+    }
+    let config = read_chain(&chain).unwrap_or_revert_with(BridgeError::InvalidChain);
+    let remote_token = parse_token_address(config.address_format, &remote_token).unwrap_or_revert();
+
+    // 手续费上下限由 set_route_fee_limits 单独维护，更新路由时保留
+    let old = read_asset_route(&asset, &chain);
+    let (min_fee, max_fee) = old
+        .as_ref()
+        .map(|route| (route.min_fee, route.max_fee))
+        .unwrap_or_default();
+    let route = AssetRoute {
+        asset: asset.clone(),
+        chain: chain.clone(),
+        remote_token,
+        remote_decimals,
+        enabled,
+        min_amount,
+        max_amount,
+        min_fee,
+        max_fee,
+    };
+    write_asset_route(route.clone());
+    emit(AssetRouteUpdated {
+        asset,
+        chain,
+        old,
+        new: route,
+    });
+}
 
-    mark_tx_processed(&tx_id);
-    emit(CsprLockedForTarget {
-        sender: Key::Account(caller.into()),
-        dst_chain: dst_chain.clone(),
-        recipient: recipient.clone(),
+/// 查询资产配置
+pub fn get_asset(id: String) -> AssetConfig {
+    read_asset(&id).unwrap_or_revert_with(BridgeError::InvalidAsset)
+}
+
+/// 查询资产在指定链上的映射
+pub fn get_asset_route(asset: String, chain: String) -> AssetRoute {
+    read_asset_route(&asset, &chain).unwrap_or_revert_with(BridgeError::InvalidAsset)
+}
+
+/// 查询当前开放的资产
+pub fn get_supported_assets() -> Vec<String> {
+    read_asset_ids()
+        .into_iter()
+        .filter(|id| read_asset(id).map(|a| a.enabled).unwrap_or(false))
+        .collect()
+}
+
+/// 通用出站：锁定 lock/unlock 模式的资产（原生 CSPR 或 CEP-18），等待目标链释放/铸造
+pub fn lock_asset(
+    asset: String,
+    amount: U256,
+    tx_id: String,
+    dst_chain: String,
+    recipient: String,
+    relayer_tip: Option<U256>,
+) {
+    ensure_not_paused();
+    let (config, route, recipient) = prepare_outbound(
+        &asset,
+        ASSET_MODE_LOCK_UNLOCK,
+        &dst_chain,
+        &recipient,
         amount,
+        &tx_id,
+    );
+    let (credited, fee, relayer_tip) =
+        lock_into_bridge(&config, &route, amount, relayer_tip.unwrap_or_default());
+    let remote_amount = to_remote(&config, &route, credited);
+    let remote_relayer_tip = to_remote(&config, &route, relayer_tip);
+
+    mark_outbound_tx_used(&tx_id);
+    emit_outbound(AssetLocked {
+        asset,
+        sender: Key::Account(runtime::get_caller().into()),
+        dst_chain,
+        recipient,
+        remote_token: route.remote_token,
+        amount: credited,
+        fee,
+        relayer_tip,
+        remote_amount,
+        remote_relayer_tip,
         tx_id,
-        event_type: EventType::CSPR_LOCKED_FOR_TARGET,
     });
 }
 
-/// ETH -> CSPR：创建 ceETH 铸造请求（由 Relayer 发起）
-pub fn create_ceeth_mint_request(
-    request_id: String,
+/// 通用入站：创建 mint/burn 模式资产的铸造请求（由 Relayer 发起）
+///
+/// 请求 ID 由合约根据源链事件计算，同一笔源链存款无法被重复铸造
+#[allow(clippy::too_many_arguments)]
+pub fn create_mint_request(
+    src_chain: String,
+    src_tx_hash: String,
+    log_index: u64,
     recipient: Key,
     amount: U256,
-    src_chain: String,
+    asset: String,
     dst_chain: String,
+    relayer_tip: Option<U256>,
 ) {
     ensure_not_paused();
+    ensure_registered_relayer();
     if amount.is_zero() {
         runtime::revert(BridgeError::InvalidAmount);
     }
-    if is_tx_processed(&request_id) {
+    let config = ensure_asset(&asset, ASSET_MODE_MINT_BURN);
+    ensure_mint_permitted(&config);
+    // 交易哈希统一存为规范形式，请求摘要与事件中的写法与 ID 一致
+    let src_tx_hash = canonical_tx_hash(&src_tx_hash).unwrap_or_revert();
+    let route = ensure_asset_route(&asset, &src_chain);
+    // 金额与小费按源链事件原样提交（源链精度），换算为本链精度后再校验
+    let remote_amount = amount;
+    let amount = to_local(&config, &route, remote_amount);
+    ensure_chain(&src_chain);
+    ensure_route_amount(&route, amount);
+    ensure_local_chain(&dst_chain);
+    // 源链资金已经锁定，小费超过上限时截断而不是 revert，超出部分归接收人
+    let relayer_tip =
+        to_local(&config, &route, relayer_tip.unwrap_or_default()).min(max_relayer_tip(amount));
+    ensure_inbound_payable(&asset, &src_chain, amount, relayer_tip);
+    let request_id = compute_message_id(
+        &src_chain,
+        &src_tx_hash,
+        log_index,
+        &recipient,
+        remote_amount,
+        &asset,
+    )
+    .unwrap_or_revert();
+    if is_inbound_processed(&request_id) {
         runtime::revert(BridgeError::TxAlreadyProcessed);
     }
+    claim_source_event(&src_chain, &src_tx_hash, log_index, &request_id);
 
+    let required_weight = required_approval_weight(
+        amount,
+        &read_approval_tiers(&asset),
+        read_threshold().unwrap_or_revert(),
+    );
     let req = UnlockRequest {
         id: request_id.clone(),
         recipient,
         amount,
+        remote_amount,
+        asset,
         src_chain,
+        src_tx_hash,
+        log_index,
         dst_chain,
         timestamp_ms: now_ms(),
         finalized: false,
         approvals_weight: 0,
+        required_weight,
+        relayer_tip,
+        submitter: Key::Account(runtime::get_caller().into()),
+        rejections_weight: 0,
+        rejected: false,
     };
+    let key = StorageKey::MintRequest(&req.asset, &request_id);
+    if is_existing_request(key, &req) {
+        return;
+    }
 
-    write_dictionary_value(DICT_CEETH_MINT_REQS, &request_id, req);
+    write_record(key, req.clone());
+    emit(MintRequested {
+        request_id,
+        recipient,
+        amount,
+        remote_amount,
+        asset: req.asset,
+        src_chain: req.src_chain,
+        src_tx_hash: req.src_tx_hash,
+        log_index,
+        dst_chain: req.dst_chain,
+        relayer_tip,
+        submitter: req.submitter,
+        required_weight,
+    });
 }
 
-/// 守护/管理员审批 ceETH 铸造；达到阈值后直接 mint
-pub fn approve_ceeth_mint(request_id: String) {
+/// 通用出站：销毁 mint/burn 模式的包装资产，目标链释放原生资产
+pub fn burn_asset(
+    asset: String,
+    amount: U256,
+    tx_id: String,
+    dst_chain: String,
+    recipient: String,
+) {
     ensure_not_paused();
+    let (config, route, recipient) = prepare_outbound(
+        &asset,
+        ASSET_MODE_MINT_BURN,
+        &dst_chain,
+        &recipient,
+        amount,
+        &tx_id,
+    );
+    adapter_for(&config).burn(amount);
+    update_reserves(&config.id, |r| {
+        r.total_burned = r.total_burned.saturating_add(amount)
+    });
+    let remote_amount = to_remote(&config, &route, amount);
 
-    let caller = runtime::get_caller();
-    let admin = get_admin();
-    let weight = match admin {
-        Key::Account(account_hash) => {
-            if account_hash != caller {
-                runtime::revert(BridgeError::PermissionDenied);
-            } else {
-                read_threshold().unwrap_or_revert() as u32 //管理员自动通过
-            }
-        }
-        _ => get_guardian_weight(&Key::Account(caller.into())).unwrap_or(0) as u32,
-    };
+    mark_outbound_tx_used(&tx_id);
+    emit_outbound(AssetBurned {
+        asset,
+        sender: Key::Account(runtime::get_caller().into()),
+        dst_chain,
+        recipient,
+        remote_token: route.remote_token,
+        amount,
+        remote_amount,
+        tx_id,
+    });
+}
 
-    if weight == 0 {
-        runtime::revert(BridgeError::PermissionDenied);
+/// 记录 Bridge 是否已被加入资产 CEP-18 合约的 minter 列表（仅管理员）
+///
+/// 授权需在代币合约侧通过 change_security 完成；登记为已授权前会向 Bridge 自身试铸 0 个代币，
+/// Bridge 不是 minter 时整个调用 revert
+pub fn set_mint_authorized(asset: String, authorized: bool) {
+    ensure_admin();
+    let config = read_asset(&asset).unwrap_or_revert_with(BridgeError::InvalidAsset);
+    if config.mode != ASSET_MODE_MINT_BURN {
+        runtime::revert(BridgeError::InvalidAsset);
     }
-
-    let vote_key = format!(
-        "ceeth_mint_vote:{}:{}",
-        request_id,
-        caller.to_formatted_string()
-    );
-    if is_tx_processed(&vote_key) {
-        return;
+    let token = config
+        .token
+        .and_then(key_to_contract_hash)
+        .unwrap_or_revert_with(BridgeError::InvalidAsset);
+    if authorized {
+        Cep18Adapter::new(token).ensure_minter();
     }
-    mark_tx_processed(&vote_key);
-
-    let mut req: UnlockRequest = read_dictionary_value(DICT_CEETH_MINT_REQS, &request_id)
-        .unwrap_or_revert_with(BridgeError::InvalidRequest);
+    let old_authorized = read_mint_authorized(&asset);
+    write_mint_authorized(&asset, authorized);
+    emit(MintAuthorizationChanged {
+        asset,
+        old_authorized,
+        new_authorized: authorized,
+    });
+}
 
-    if req.finalized {
-        runtime::revert(BridgeError::InvalidRequest);
-    }
+// =========================
+// 新增：跨链 ceETH / wCSPR
+// =========================
 
-    req.approvals_weight = req.approvals_weight.saturating_add(weight);
+/// 设置 ceETH 合约哈希（仅管理员），并登记为 mint/burn 资产
+pub fn set_ceeth_token_entry(token: Key) {
+    ensure_admin();
+    ensure_token_decimals(token, CEETH_DECIMALS);
+    let old_token = read_ceeth_token();
+    set_ceeth_token(token);
+    emit(CeETHTokenSet {
+        old_token,
+        new_token: token,
+    });
+    save_asset(AssetConfig {
+        id: ASSET_CEETH.into(),
+        token: Some(token),
+        decimals: CEETH_DECIMALS,
+        mode: ASSET_MODE_MINT_BURN,
+        enabled: read_asset(ASSET_CEETH).map(|a| a.enabled).unwrap_or(true),
+    });
+}
 
-    if req.approvals_weight >= read_threshold().unwrap_or_revert() as u32 {
-        let token = get_ceeth_token();
-        // cep18_mint(token.clone(), req.recipient, req.amount).unwrap_or_revert();
+/// CSPR -> ETH：锁仓 CSPR（计息资产记账），等待对端 mint wCSPR
+pub fn lock_cspr_for_eth(
+    amount: U256,
+    tx_id: String,
+    dst_chain: String,
+    recipient: String,
+    relayer_tip: Option<U256>,
+) {
+    ensure_not_paused();
+    let (config, route, recipient) = prepare_outbound(
+        ASSET_CSPR,
+        ASSET_MODE_LOCK_UNLOCK,
+        &dst_chain,
+        &recipient,
+        amount,
+        &tx_id,
+    );
+    let (credited, fee, relayer_tip) =
+        lock_into_bridge(&config, &route, amount, relayer_tip.unwrap_or_default());
+    let remote_amount = to_remote(&config, &route, credited);
+    let remote_relayer_tip = to_remote(&config, &route, relayer_tip);
+
+    mark_outbound_tx_used(&tx_id);
+    emit_outbound(CsprLockedForTarget {
+        sender: Key::Account(runtime::get_caller().into()),
+        dst_chain,
+        recipient,
+        amount: credited,
+        tx_id,
+        fee,
+        relayer_tip,
+        remote_amount,
+        remote_relayer_tip,
+    });
+}
 
-        req.finalized = true;
-        mark_tx_processed(&request_id);
-        emit(CeETHMinted {
-            recipient: req.recipient,
-            amount: req.amount,
-            tx_id: req.id.clone(),
-            event_type: EventType::CEETH_MINTED,
-        });
+/// ETH -> CSPR：创建 ceETH 铸造请求（由 Relayer 发起）
+#[allow(clippy::too_many_arguments)]
+pub fn create_ceeth_mint_request(
+    src_chain: String,
+    src_tx_hash: String,
+    log_index: u64,
+    recipient: Key,
+    amount: U256,
+    asset: String,
+    dst_chain: String,
+    relayer_tip: Option<U256>,
+) {
+    if asset != ASSET_CEETH {
+        runtime::revert(BridgeError::InvalidAsset);
     }
+    create_mint_request(
+        src_chain,
+        src_tx_hash,
+        log_index,
+        recipient,
+        amount,
+        asset,
+        dst_chain,
+        relayer_tip,
+    );
+}
 
-    write_dictionary_value(DICT_CEETH_MINT_REQS, &request_id, req);
+/// 守护节点审批 ceETH 铸造请求
+pub fn approve_ceeth_mint(request_id: String, payload_digest: String) {
+    approve_mint(ASSET_CEETH.into(), request_id, payload_digest);
 }
 
-/// 用户燃烧 ceETH 以赎回 ETH（on-chain 事件由 Relayer 监听并在 ETH 链释放）
-pub fn burn_ceeth_for_eth(amount: U256, tx_id: String, eth_owner: String) {
+/// 守护节点审批 mint/burn 资产的铸造请求（权重累加）；达到阈值后直接 mint
+///
+/// payload_digest 为与源链核对后的请求摘要，与链上请求不一致时拒绝投票
+pub fn approve_mint(asset: String, request_id: String, payload_digest: String) {
     ensure_not_paused();
-    if amount.is_zero() {
-        runtime::revert(BridgeError::InvalidAmount);
-    }
-    if is_tx_processed(&tx_id) {
-        runtime::revert(BridgeError::TxAlreadyProcessed);
-    }
+    let (voter, weight) = ensure_guardian();
 
-    let caller = runtime::get_caller();
-    let bridge_package_key = runtime::get_key("bridge_core_package_hash").unwrap_or_revert();
-    let ceeth_contract_key = get_ceeth_token(); //ceETH contract hash
+    let key = StorageKey::MintRequest(&asset, &request_id);
+    let mut req = read_request_for_approval(key, &payload_digest);
 
-    let bridge_package_hash = key_to_contract_hash(bridge_package_key).unwrap_or_revert();
-    let ceeth_contract_hash = key_to_contract_hash(ceeth_contract_key).unwrap_or_revert();
+    if !record_vote(StorageKey::MintVote(&payload_digest, &voter)) {
+        return;
+    }
 
-    /// First: transfer ceETH from caller to bridge contract
-    let transfer_args = runtime_args! {
-        "owner" => caller,
-        "recipient" => bridge_package_hash, // 转给我(Bridge)
-        "amount" => amount
-    };
+    req.approvals_weight = req.approvals_weight.saturating_add(weight);
+    emit(GuardianVoted {
+        subject: request_id.clone(),
+        kind: VOTE_KIND_MINT,
+        guardian: voter,
+        weight,
+        approvals_weight: req.approvals_weight,
+        required_weight: req.required_weight,
+    });
 
-    runtime::call_contract::<()>(ceeth_contract_hash, "transfer_from", transfer_args);
+    if req.approvals_weight >= req.required_weight {
+        release_or_queue(&mut req, RELEASE_KIND_MINT);
+    }
 
-    /// Second: burn ceETH from bridge contract
-    let burn_args = runtime_args! {
-        "amount" => amount,
-        "owner" => bridge_package_hash
-    };
+    write_record(key, req);
+}
 
-    runtime::call_contract::<()>(
-        ceeth_contract_hash,
-        "burn", // 调用者是 Bridge，所以销毁的是 Bridge 的余额
-        burn_args,
+/// 守护节点拒绝内容与源链不符的铸造请求（规则同 reject_unlock）
+pub fn reject_mint(asset: String, request_id: String, payload_digest: String) {
+    ensure_not_paused();
+    let (guardian, weight) = ensure_guardian();
+    let key = StorageKey::MintRequest(&asset, &request_id);
+    let mut req = read_request_for_rejection(key, &payload_digest);
+    vote_reject(&mut req, guardian, weight);
+    write_record(key, req);
+}
+
+/// 用户燃烧 ceETH 以赎回 ETH（on-chain 事件由 Relayer 监听并在 ETH 链释放）
+pub fn burn_ceeth_for_eth(amount: U256, tx_id: String, dst_chain: String, eth_owner: String) {
+    ensure_not_paused();
+    let (config, route, eth_owner) = prepare_outbound(
+        ASSET_CEETH,
+        ASSET_MODE_MINT_BURN,
+        &dst_chain,
+        &eth_owner,
+        amount,
+        &tx_id,
     );
+    adapter_for(&config).burn(amount);
+    update_reserves(&config.id, |r| {
+        r.total_burned = r.total_burned.saturating_add(amount)
+    });
 
-    mark_tx_processed(&tx_id);
-    emit(CeETHBurned {
-        eth_owner: eth_owner,
+    mark_outbound_tx_used(&tx_id);
+    emit_outbound(CeETHBurned {
+        eth_owner,
+        dst_chain,
         amount,
+        remote_amount: to_remote(&config, &route, amount),
         tx_id,
-        event_type: EventType::CEETH_BURNED,
     });
 }
+
+// =========================
+// 供应量不变量与储备证明
+
+/// 查询资产的供应量计数
+pub fn get_reserves(asset: String) -> AssetReserves {
+    read_reserves(&asset)
+}
+
+/// 校验资产供应量不变量，不满足时 revert InvariantViolated（任何人均可调用）
+///
+/// mint/burn 资产还会读取 CEP-18 的 total_supply，确保实际流通量有远端锁仓支撑
+pub fn assert_invariants(asset: String) {
+    let config = read_asset(&asset).unwrap_or_revert_with(BridgeError::InvalidAsset);
+    let token_supply = if config.mode == ASSET_MODE_MINT_BURN && config.token.is_some() {
+        Some(adapter_for(&config).total_supply())
+    } else {
+        None
+    };
+    check_supply_invariants(&read_reserves(&asset), token_supply).unwrap_or_revert();
+}
+
+/// 查询出站哈希链的链头、事件数与最近的序号
+pub fn get_outbound_accumulator() -> OutboundAccumulator {
+    read_outbound_accumulator()
+}
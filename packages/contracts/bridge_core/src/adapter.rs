@@ -0,0 +1,252 @@
+//! 资产适配层：原生 CSPR 与 CEP-18 的资金流转统一走同一套接口
+extern crate alloc;
+
+use alloc::boxed::Box;
+use casper_contract::{
+    contract_api::{account, runtime, system},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{contracts::ContractHash, runtime_args, Key, URef, U256, U512};
+
+use crate::{
+    storage::get_bridge_purse,
+    types::{AssetConfig, BridgeError, CSPR_DECIMALS},
+};
+
+/// Bridge 托管资产的统一操作
+///
+/// 所有方法都以 Bridge 为托管方：pull 收入托管，push 从托管支付，
+/// burn 先收回调用者的资产再由 Bridge 销毁。
+pub trait AssetAdapter {
+    /// 将调用者的资产转入 Bridge 托管
+    fn pull(&self, amount: U256);
+    /// 从 Bridge 托管向 recipient 支付（金额为 0 时不操作）
+    fn push(&self, recipient: Key, amount: U256);
+    /// 为 owner 铸造资产（金额为 0 时不操作）
+    fn mint(&self, owner: Key, amount: U256);
+    /// 收回调用者的资产并销毁
+    fn burn(&self, amount: U256);
+    /// 查询 owner 的余额
+    ///
+    /// 原生 CSPR 只能查询 Bridge 合约包自身的托管余额，其他 owner 会 revert InvalidAddress
+    fn balance_of(&self, owner: Key) -> U256;
+    /// 查询资产的总流通量
+    fn total_supply(&self) -> U256;
+    /// 资产自身的精度
+    fn decimals(&self) -> u8;
+}
+
+/// 将 Key 转换为 ContractHash
+pub fn key_to_contract_hash(key: Key) -> Option<ContractHash> {
+    // 1. 模式匹配解包
+    if let Key::Hash(hash_addr) = key {
+        // 2. HashAddr 本质就是 [u8; 32]，ContractHash 也是 [u8; 32]
+        // 所以直接返回即可，或者显式转换
+        Some(ContractHash::new(hash_addr))
+    } else {
+        None // 如果这个 Key 不是 Hash 类型（比如是 Account 或 URef），则无法转换
+    }
+}
+
+/// Bridge 合约包自身的 Key（托管的 CEP-18 余额记在该 Key 名下）
+pub fn bridge_package_key() -> Key {
+    runtime::get_key("bridge_core_package_hash").unwrap_or_revert()
+}
+
+/// 按资产配置选择适配器：无代币合约为原生 CSPR，否则为 CEP-18
+pub fn adapter_for(asset: &AssetConfig) -> Box<dyn AssetAdapter> {
+    match asset.token {
+        None => Box::new(NativeAdapter::new()),
+        Some(token) => Box::new(Cep18Adapter::new(
+            key_to_contract_hash(token).unwrap_or_revert_with(BridgeError::TokenNotSet),
+        )),
+    }
+}
+
+/// U256 → U512 无损转换（不经过 u128，超过 u128 的金额不会 panic）
+fn to_motes(amount: U256) -> U512 {
+    let mut bytes = [0u8; 32];
+    amount.to_little_endian(&mut bytes);
+    U512::from_little_endian(&bytes)
+}
+
+/// U512 → U256，超出 U256 范围时 revert InvalidAmount
+fn from_motes(motes: U512) -> U256 {
+    if motes > to_motes(U256::MAX) {
+        runtime::revert(BridgeError::InvalidAmount);
+    }
+    let mut bytes = [0u8; 64];
+    motes.to_little_endian(&mut bytes);
+    U256::from_little_endian(&bytes[..32])
+}
+
+/// 原生 CSPR：托管在 Bridge 钱包 purse 中，不支持铸造与销毁
+pub struct NativeAdapter {
+    purse: URef,
+}
+
+impl NativeAdapter {
+    pub fn new() -> Self {
+        Self {
+            purse: get_bridge_purse(),
+        }
+    }
+}
+
+impl Default for NativeAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AssetAdapter for NativeAdapter {
+    fn pull(&self, amount: U256) {
+        system::transfer_from_purse_to_purse(
+            account::get_main_purse(),
+            self.purse,
+            to_motes(amount),
+            None,
+        )
+        .unwrap_or_revert_with(BridgeError::TransferFailed);
+    }
+
+    fn push(&self, recipient: Key, amount: U256) {
+        if amount.is_zero() {
+            return;
+        }
+        let target = match recipient {
+            Key::Account(account_hash) => account_hash,
+            _ => runtime::revert(BridgeError::InvalidAddress),
+        };
+        system::transfer_from_purse_to_account(self.purse, target, to_motes(amount), None)
+            .unwrap_or_revert_with(BridgeError::TransferFailed);
+    }
+
+    fn mint(&self, _owner: Key, _amount: U256) {
+        runtime::revert(BridgeError::InvalidAsset);
+    }
+
+    fn burn(&self, _amount: U256) {
+        runtime::revert(BridgeError::InvalidAsset);
+    }
+
+    /// 只能查询 Bridge 自身的托管余额
+    ///
+    /// 合约无法读取其他账户的 main purse，owner 不是 Bridge 合约包时 revert InvalidAddress
+    fn balance_of(&self, owner: Key) -> U256 {
+        if owner != bridge_package_key() {
+            runtime::revert(BridgeError::InvalidAddress);
+        }
+        from_motes(system::get_purse_balance(self.purse).unwrap_or_default())
+    }
+
+    fn total_supply(&self) -> U256 {
+        runtime::revert(BridgeError::InvalidAsset);
+    }
+
+    fn decimals(&self) -> u8 {
+        CSPR_DECIMALS
+    }
+}
+
+/// CEP-18 代币：托管余额记在 Bridge 合约包名下
+pub struct Cep18Adapter {
+    token: ContractHash,
+    custodian: Key,
+}
+
+impl Cep18Adapter {
+    pub fn new(token: ContractHash) -> Self {
+        Self {
+            token,
+            custodian: bridge_package_key(),
+        }
+    }
+
+    /// 向 Bridge 自身铸造 0 个代币，确认 Bridge 持有代币合约的 minter 权限
+    ///
+    /// CEP-18 先校验调用者的权限再处理金额，Bridge 不在 minter 列表时以代币自身的错误码 revert
+    pub fn ensure_minter(&self) {
+        runtime::call_contract::<()>(
+            self.token,
+            "mint",
+            runtime_args! {
+                "owner" => self.custodian,
+                "amount" => U256::zero(),
+            },
+        );
+    }
+}
+
+impl AssetAdapter for Cep18Adapter {
+    fn pull(&self, amount: U256) {
+        runtime::call_contract::<()>(
+            self.token,
+            "transfer_from",
+            runtime_args! {
+                "owner" => Key::Account(runtime::get_caller().into()),
+                "recipient" => self.custodian,
+                "amount" => amount,
+            },
+        );
+    }
+
+    fn push(&self, recipient: Key, amount: U256) {
+        if amount.is_zero() {
+            return;
+        }
+        runtime::call_contract::<()>(
+            self.token,
+            "transfer",
+            runtime_args! {
+                "recipient" => recipient,
+                "amount" => amount,
+            },
+        );
+    }
+
+    fn mint(&self, owner: Key, amount: U256) {
+        if amount.is_zero() {
+            return;
+        }
+        runtime::call_contract::<()>(
+            self.token,
+            "mint",
+            runtime_args! {
+                "owner" => owner,
+                "amount" => amount,
+            },
+        );
+    }
+
+    fn burn(&self, amount: U256) {
+        // 先将资产从调用者转给 Bridge，再由 Bridge 销毁自身余额
+        self.pull(amount);
+        runtime::call_contract::<()>(
+            self.token,
+            "burn",
+            runtime_args! {
+                "owner" => self.custodian,
+                "amount" => amount,
+            },
+        );
+    }
+
+    fn balance_of(&self, owner: Key) -> U256 {
+        runtime::call_contract::<U256>(
+            self.token,
+            "balance_of",
+            runtime_args! {
+                "address" => owner,
+            },
+        )
+    }
+
+    fn total_supply(&self) -> U256 {
+        runtime::call_contract::<U256>(self.token, "total_supply", runtime_args! {})
+    }
+
+    fn decimals(&self) -> u8 {
+        runtime::call_contract::<u8>(self.token, "decimals", runtime_args! {})
+    }
+}
@@ -0,0 +1,99 @@
+//! 目标链地址编解码
+extern crate alloc;
+
+use alloc::{string::String, vec::Vec};
+use tiny_keccak::{Hasher, Keccak};
+
+use crate::types::{BridgeError, ADDRESS_FORMAT_EVM};
+
+/// EVM 地址字节长度
+pub const EVM_ADDRESS_LEN: usize = 20;
+
+/// 按链注册表中的地址格式解析接收地址，返回规范化的字节形式
+pub fn parse_address(address_format: u8, input: &str) -> Result<Vec<u8>, BridgeError> {
+    match address_format {
+        ADDRESS_FORMAT_EVM => parse_evm_address(input).map(|bytes| bytes.to_vec()),
+        _ => Err(BridgeError::InvalidAddress),
+    }
+}
+
+/// 按地址格式解析远端代币地址；零地址表示目标链原生资产，允许使用
+pub fn parse_token_address(address_format: u8, input: &str) -> Result<Vec<u8>, BridgeError> {
+    match address_format {
+        ADDRESS_FORMAT_EVM => decode_evm_address(input).map(|bytes| bytes.to_vec()),
+        _ => Err(BridgeError::InvalidAddress),
+    }
+}
+
+/// 地址格式是否受支持
+pub fn is_supported_address_format(address_format: u8) -> bool {
+    address_format == ADDRESS_FORMAT_EVM
+}
+
+/// 解析 20 字节 EVM 接收地址
+///
+/// 全小写或全大写视为未带校验和；大小写混合时必须符合 EIP-55 校验和。
+/// 零地址无人持有私钥，转入即销毁，返回 InvalidRecipient。
+pub fn parse_evm_address(input: &str) -> Result<[u8; EVM_ADDRESS_LEN], BridgeError> {
+    let bytes = decode_evm_address(input)?;
+    if bytes == [0u8; EVM_ADDRESS_LEN] {
+        return Err(BridgeError::InvalidRecipient);
+    }
+    Ok(bytes)
+}
+
+/// 解码 20 字节 EVM hex 地址并校验 EIP-55 校验和（不限制零地址）
+fn decode_evm_address(input: &str) -> Result<[u8; EVM_ADDRESS_LEN], BridgeError> {
+    let body = input
+        .strip_prefix("0x")
+        .or_else(|| input.strip_prefix("0X"))
+        .ok_or(BridgeError::InvalidAddress)?;
+    if body.len() != EVM_ADDRESS_LEN * 2 {
+        return Err(BridgeError::InvalidAddress);
+    }
+
+    let mut bytes = [0u8; EVM_ADDRESS_LEN];
+    hex::decode_to_slice(body, &mut bytes).map_err(|_| BridgeError::InvalidAddress)?;
+
+    let has_lower = body.bytes().any(|c| c.is_ascii_lowercase());
+    let has_upper = body.bytes().any(|c| c.is_ascii_uppercase());
+    if has_lower && has_upper && body != checksum_hex(&bytes) {
+        return Err(BridgeError::InvalidAddress);
+    }
+
+    Ok(bytes)
+}
+
+/// 生成 EIP-55 校验和格式的地址（带 0x 前缀）
+pub fn to_checksum_address(bytes: &[u8; EVM_ADDRESS_LEN]) -> String {
+    let mut out = String::from("0x");
+    out.push_str(&checksum_hex(bytes));
+    out
+}
+
+/// EIP-55：对小写 hex 做 keccak256，哈希对应半字节 >= 8 的字母位大写
+fn checksum_hex(bytes: &[u8; EVM_ADDRESS_LEN]) -> String {
+    let lower = hex::encode(bytes);
+
+    let mut hash = [0u8; 32];
+    let mut hasher = Keccak::v256();
+    hasher.update(lower.as_bytes());
+    hasher.finalize(&mut hash);
+
+    lower
+        .chars()
+        .enumerate()
+        .map(|(i, c)| {
+            let nibble = if i % 2 == 0 {
+                hash[i / 2] >> 4
+            } else {
+                hash[i / 2] & 0x0f
+            };
+            if nibble >= 8 {
+                c.to_ascii_uppercase()
+            } else {
+                c
+            }
+        })
+        .collect()
+}
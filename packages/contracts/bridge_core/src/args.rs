@@ -0,0 +1,48 @@
+//! 客户端参数构造：为各入口点生成类型化的 RuntimeArgs
+//!
+//! 参数名与类型取自 registry 登记表，与合约中的 EntryPoint 定义一致，链下服务无需手写参数名
+use alloc::{string::String, vec::Vec};
+use casper_types::{Key, RuntimeArgs, U256};
+
+use crate::types::{ApprovalTier, Guardian};
+
+/// 为登记表中的每个入口点生成同名的参数构造函数
+macro_rules! entry_point_args {
+    ($($name:ident($($arg:ident: $ty:ty),* $(,)?) $(-> $ret:ty)? $(=> $handler:ident)?;)*) => {
+        $(
+            #[allow(clippy::too_many_arguments)]
+            pub fn $name($($arg: $ty),*) -> RuntimeArgs {
+                #[allow(unused_mut)]
+                let mut args = RuntimeArgs::new();
+                $(args.insert(stringify!($arg), $arg).expect("参数序列化失败");)*
+                args
+            }
+        )*
+    };
+}
+
+/// 安装会话参数；ceeth_token 为 None 时不链接 ceETH 代币，
+/// revoked_minters 为链接时需撤销权限的代币现有 minter/admin
+pub fn install(
+    admin: Key,
+    guardians: Vec<Guardian>,
+    threshold: u32,
+    base_apr_bps: u32,
+    ceeth_token: Option<Key>,
+    revoked_minters: Vec<Key>,
+) -> RuntimeArgs {
+    let mut args = RuntimeArgs::new();
+    args.insert("admin", admin).expect("参数序列化失败");
+    args.insert("guardians", guardians).expect("参数序列化失败");
+    args.insert("threshold", threshold).expect("参数序列化失败");
+    args.insert("base_apr_bps", base_apr_bps)
+        .expect("参数序列化失败");
+    if let Some(token) = ceeth_token {
+        args.insert("ceeth_token", token).expect("参数序列化失败");
+        args.insert("ceeth_revoked_minters", revoked_minters)
+            .expect("参数序列化失败");
+    }
+    args
+}
+
+entry_point_registry!(entry_point_args);
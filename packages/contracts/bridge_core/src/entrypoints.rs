@@ -1,19 +1,20 @@
 extern crate alloc;
 
-use alloc::{string::String, vec::Vec};
-use casper_contract::contract_api::{runtime, storage};
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
 use casper_types::{
-    contracts::{EntryPoint, EntryPoints},
+    contract_messages::MessageTopicOperation,
+    contracts::{ContractHash, EntryPoint, EntryPoints, NamedKeys},
     runtime_args, CLType, CLTyped, CLValue, EntryPointAccess, EntryPointType, Key, Parameter, U256,
 };
 
-use crate::{
-    actions::{
-        approve_ceeth_mint, approve_hot_swap, approve_unlock, burn_ceeth_for_eth,
-        create_ceeth_mint_request, create_unlock_request, lock_cspr_for_eth, propose_hot_swap,
-        set_ceeth_token_entry, set_pause, transfer_admin, update_apr,
-    },
-    types::{Guardian, VaultPosition},
+use crate::types::{
+    ApprovalTier, AssetConfig, AssetReserves, AssetRoute, BridgeError, ChainConfig, Guardian,
+    OutboundAccumulator, PendingRelease, RateLimit, RelayerInfo, ReleaseDelay, SolvencyReport,
+    VaultPosition, ASSET_CEETH,
 };
 
 // ==============
@@ -26,168 +27,34 @@ pub extern "C" fn call() {
     let guardians: Vec<Guardian> = runtime::get_named_arg("guardians");
     let threshold: u32 = runtime::get_named_arg("threshold");
     let base_apr_bps: u32 = runtime::get_named_arg("base_apr_bps");
+    let ceeth_token = runtime::try_get_named_arg::<Key>("ceeth_token");
+    let revoked_minters: Vec<Key> =
+        runtime::try_get_named_arg("ceeth_revoked_minters").unwrap_or_default();
 
-    let mut entry_points = EntryPoints::new();
-
-    entry_points.add_entry_point(EntryPoint::new(
-        "init",
-        alloc::vec![
-            Parameter::new("admin", Key::cl_type()),
-            Parameter::new("guardians", <Vec<Guardian> as CLTyped>::cl_type()),
-            Parameter::new("threshold", u32::cl_type()),
-            Parameter::new("base_apr_bps", u32::cl_type()),
-        ],
-        CLType::Unit,
-        EntryPointAccess::Public,
-        EntryPointType::Called,
-    ));
-
-    entry_points.add_entry_point(EntryPoint::new(
-        "lock",
-        alloc::vec![
-            Parameter::new("amount", U256::cl_type()),
-            Parameter::new("dst_chain", String::cl_type()),
-            Parameter::new("tx_id", String::cl_type()),
-        ],
-        CLType::Unit,
-        EntryPointAccess::Public,
-        EntryPointType::Called,
-    ));
-
-    entry_points.add_entry_point(EntryPoint::new(
-        "create_unlock_request",
-        alloc::vec![
-            Parameter::new("request_id", String::cl_type()),
-            Parameter::new("recipient", Key::cl_type()),
-            Parameter::new("amount", U256::cl_type()),
-            Parameter::new("src_chain", String::cl_type()),
-            Parameter::new("dst_chain", String::cl_type()),
-        ],
-        CLType::Unit,
-        EntryPointAccess::Public,
-        EntryPointType::Called,
-    ));
-
-    entry_points.add_entry_point(EntryPoint::new(
-        "approve_unlock",
-        alloc::vec![Parameter::new("request_id", String::cl_type())],
-        CLType::Unit,
-        EntryPointAccess::Public,
-        EntryPointType::Called,
-    ));
-
-    entry_points.add_entry_point(EntryPoint::new(
-        "propose_hot_swap",
-        alloc::vec![Parameter::new("patch_hash", String::cl_type())],
-        CLType::Unit,
-        EntryPointAccess::Public,
-        EntryPointType::Called,
-    ));
-
-    entry_points.add_entry_point(EntryPoint::new(
-        "approve_hot_swap",
-        alloc::vec![Parameter::new("patch_hash", String::cl_type())],
-        CLType::Unit,
-        EntryPointAccess::Public,
-        EntryPointType::Called,
-    ));
-
-    entry_points.add_entry_point(EntryPoint::new(
-        "set_pause",
-        alloc::vec![Parameter::new("paused", bool::cl_type())],
-        CLType::Unit,
-        EntryPointAccess::Public,
-        EntryPointType::Called,
-    ));
+    // 关联代币时随后以部署者身份调用仅管理员可用的入口点，部署者必须就是 admin；
+    // 在创建合约包之前检查，避免部署到一半才失败
+    let installer = Key::Account(runtime::get_caller());
+    if ceeth_token.is_some() && installer != admin {
+        runtime::revert(BridgeError::PermissionDenied);
+    }
 
-    entry_points.add_entry_point(EntryPoint::new(
-        "update_apr",
-        alloc::vec![Parameter::new("new_apr_bps", u32::cl_type())],
-        CLType::Unit,
-        EntryPointAccess::Public,
-        EntryPointType::Called,
-    ));
-
-    entry_points.add_entry_point(EntryPoint::new(
-        "transfer_admin",
-        alloc::vec![Parameter::new("new_admin", Key::cl_type())],
-        CLType::Unit,
-        EntryPointAccess::Public,
-        EntryPointType::Called,
-    ));
-
-    entry_points.add_entry_point(EntryPoint::new(
-        "get_position",
-        alloc::vec![Parameter::new("account", Key::cl_type())],
-        <VaultPosition as CLTyped>::cl_type(),
-        EntryPointAccess::Public,
-        EntryPointType::Called,
-    ));
-
-    entry_points.add_entry_point(EntryPoint::new(
-        "set_ceeth_token",
-        alloc::vec![Parameter::new("token", Key::cl_type())],
-        CLType::Unit,
-        EntryPointAccess::Public,
-        EntryPointType::Called,
-    ));
-
-    entry_points.add_entry_point(EntryPoint::new(
-        "lock_cspr_for_eth",
-        alloc::vec![
-            Parameter::new("amount", U256::cl_type()),
-            Parameter::new("tx_id", String::cl_type()),
-            Parameter::new("dst_chain", String::cl_type()),
-            Parameter::new("recipient", String::cl_type()),
-        ],
-        CLType::Unit,
-        EntryPointAccess::Public,
-        EntryPointType::Called,
-    ));
-
-    entry_points.add_entry_point(EntryPoint::new(
-        "create_ceeth_mint_request",
-        alloc::vec![
-            Parameter::new("request_id", String::cl_type()),
-            Parameter::new("recipient", Key::cl_type()),
-            Parameter::new("amount", U256::cl_type()),
-            Parameter::new("src_chain", String::cl_type()),
-            Parameter::new("dst_chain", String::cl_type()),
-        ],
-        CLType::Unit,
-        EntryPointAccess::Public,
-        EntryPointType::Called,
-    ));
-
-    entry_points.add_entry_point(EntryPoint::new(
-        "approve_ceeth_mint",
-        alloc::vec![Parameter::new("request_id", String::cl_type())],
-        CLType::Unit,
-        EntryPointAccess::Public,
-        EntryPointType::Called,
-    ));
-
-    entry_points.add_entry_point(EntryPoint::new(
-        "burn_ceeth_for_eth",
-        alloc::vec![
-            Parameter::new("amount", U256::cl_type()),
-            Parameter::new("tx_id", String::cl_type()),
-            Parameter::new("eth_owner", String::cl_type()),
-        ],
-        CLType::Unit,
-        EntryPointAccess::Public,
-        EntryPointType::Called,
-    ));
+    let entry_points = entry_points();
 
     let (package_hash, access_token) = storage::create_contract_package_at_hash();
     runtime::put_key("bridge_core_package_hash", package_hash.into());
     runtime::put_key("bridge_core_package_access", access_token.into());
 
+    // 合约上下文需要知道自身的合约包（CEP-18 余额与铸造都记在合约包名下），并注册事件主题
+    let mut named_keys = NamedKeys::new();
+    named_keys.insert("bridge_core_package_hash".into(), package_hash.into());
+    let mut message_topics = BTreeMap::new();
+    message_topics.insert("LTEvents".into(), MessageTopicOperation::Add);
+
     let (contract_hash, _version) = storage::add_contract_version(
         package_hash,
         entry_points.into(),
-        Default::default(),
-        Default::default(),
+        named_keys,
+        message_topics,
     );
 
     runtime::put_key("bridge_core_contract_hash", contract_hash.into());
@@ -203,105 +70,124 @@ pub extern "C" fn call() {
             "base_apr_bps" => base_apr_bps,
         },
     );
-}
-
-#[no_mangle]
-pub extern "C" fn init() {
-    let admin: Key = runtime::get_named_arg("admin");
-    let guardians: Vec<Guardian> = runtime::get_named_arg("guardians");
-    let threshold: u32 = runtime::get_named_arg("threshold");
-    let base_apr_bps: u32 = runtime::get_named_arg("base_apr_bps");
-    crate::actions::init(admin, guardians, threshold, base_apr_bps);
-}
-
-#[no_mangle]
-pub extern "C" fn create_unlock_request_entry() {
-    let request_id: String = runtime::get_named_arg("request_id");
-    let recipient: Key = runtime::get_named_arg("recipient");
-    let amount: U256 = runtime::get_named_arg("amount");
-    let src_chain: String = runtime::get_named_arg("src_chain");
-    let dst_chain: String = runtime::get_named_arg("dst_chain");
-    create_unlock_request(request_id, recipient, amount, src_chain, dst_chain);
-}
-
-#[no_mangle]
-pub extern "C" fn approve_unlock_entry() {
-    let request_id: String = runtime::get_named_arg("request_id");
-    approve_unlock(request_id);
-}
-
-#[no_mangle]
-pub extern "C" fn propose_hot_swap_entry() {
-    let patch_hash: String = runtime::get_named_arg("patch_hash");
-    propose_hot_swap(patch_hash);
-}
-
-#[no_mangle]
-pub extern "C" fn approve_hot_swap_entry() {
-    let patch_hash: String = runtime::get_named_arg("patch_hash");
-    approve_hot_swap(patch_hash);
-}
-
-#[no_mangle]
-pub extern "C" fn set_pause_entry() {
-    let paused: bool = runtime::get_named_arg("paused");
-    set_pause(paused);
-}
-
-#[no_mangle]
-pub extern "C" fn update_apr_entry() {
-    let new_apr_bps: u32 = runtime::get_named_arg("new_apr_bps");
-    update_apr(new_apr_bps);
-}
-
-#[no_mangle]
-pub extern "C" fn transfer_admin_entry() {
-    let new_admin: Key = runtime::get_named_arg("new_admin");
-    transfer_admin(new_admin);
-}
-
-#[no_mangle]
-pub extern "C" fn get_position_entry() {
-    let account: Key = runtime::get_named_arg("account");
-    let pos = crate::actions::get_position(account);
-    runtime::ret(CLValue::from_t(pos).unwrap());
-}
 
-#[no_mangle]
-pub extern "C" fn set_ceeth_token_entrypoint() {
-    let token: Key = runtime::get_named_arg("token");
-    set_ceeth_token_entry(token);
-}
-
-#[no_mangle]
-pub extern "C" fn lock_cspr_for_eth_entry() {
-    let amount: U256 = runtime::get_named_arg("amount");
-    let tx_id: String = runtime::get_named_arg("tx_id");
-    let dst_chain: String = runtime::get_named_arg("dst_chain");
-    let recipient: String = runtime::get_named_arg("recipient");
-    lock_cspr_for_eth(amount, tx_id, dst_chain, recipient);
+    // 可选：在同一部署中关联已部署的 ceETH（CEP-18）
+    if let Some(token) = ceeth_token {
+        link_ceeth_token(
+            contract_hash,
+            package_hash.into(),
+            installer,
+            token,
+            revoked_minters,
+        );
+    }
 }
 
-#[no_mangle]
-pub extern "C" fn create_ceeth_mint_request_entry() {
-    let request_id: String = runtime::get_named_arg("request_id");
-    let recipient: Key = runtime::get_named_arg("recipient");
-    let amount: U256 = runtime::get_named_arg("amount");
-    let src_chain: String = runtime::get_named_arg("src_chain");
-    let dst_chain: String = runtime::get_named_arg("dst_chain");
-    create_ceeth_mint_request(request_id, recipient, amount, src_chain, dst_chain);
+/// 链接已部署的 ceETH 代币：将 Bridge 合约包设为 minter 并写入 KEY_CEETH_TOKEN
+///
+/// 只链接不安装：代币须由同一 admin 先行部署，部署者须同时是 Bridge admin 与代币 admin。
+/// CEP-18 不提供查询 minter 列表的入口点，已有的其他 minter/admin 须通过 ceeth_revoked_minters
+/// 传入，与部署者一起降为无权限，Bridge 成为唯一 minter。
+fn link_ceeth_token(
+    bridge: ContractHash,
+    bridge_package: Key,
+    installer: Key,
+    token: Key,
+    revoked_minters: Vec<Key>,
+) {
+    let token_hash = token
+        .into_hash_addr()
+        .map(ContractHash::new)
+        .unwrap_or_revert_with(BridgeError::InvalidAsset);
+
+    // 销毁的是 Bridge 自身余额，无需额外权限
+    let mut none_list = alloc::vec![installer];
+    none_list.extend(
+        revoked_minters
+            .into_iter()
+            .filter(|key| *key != bridge_package && *key != installer),
+    );
+    runtime::call_contract::<()>(
+        token_hash,
+        "change_security",
+        runtime_args! {
+            "minter_list" => alloc::vec![bridge_package],
+            "none_list" => none_list,
+        },
+    );
+    runtime::call_contract::<()>(
+        bridge,
+        "set_ceeth_token",
+        runtime_args! {
+            "token" => token,
+        },
+    );
+    runtime::call_contract::<()>(
+        bridge,
+        "set_mint_authorized",
+        runtime_args! {
+            "asset" => ASSET_CEETH,
+            "authorized" => true,
+        },
+    );
 }
 
-#[no_mangle]
-pub extern "C" fn approve_ceeth_mint_entry() {
-    let request_id: String = runtime::get_named_arg("request_id");
-    approve_ceeth_mint(request_id);
+/// 由登记表生成入口点定义与导出函数
+///
+/// 导出函数按参数名读取运行时参数，调用处理函数，有返回类型时将结果 ret 给调用方
+macro_rules! contract_entry_points {
+    (@ret_type) => {
+        CLType::Unit
+    };
+    (@ret_type $ret:ty) => {
+        <$ret as CLTyped>::cl_type()
+    };
+    (@handler $name:ident) => {
+        crate::actions::$name
+    };
+    (@handler $name:ident $handler:ident) => {
+        crate::actions::$handler
+    };
+    (@dispatch $call:expr) => {
+        $call
+    };
+    (@dispatch $call:expr => $ret:ty) => {{
+        let value: $ret = $call;
+        runtime::ret(CLValue::from_t(value).unwrap())
+    }};
+    ($($name:ident($($arg:ident: $ty:ty),* $(,)?) $(-> $ret:ty)? $(=> $handler:ident)?;)*) => {
+        /// Bridge 合约的全部入口点
+        pub fn entry_points() -> EntryPoints {
+            let mut entry_points = EntryPoints::new();
+            $(
+                entry_points.add_entry_point(EntryPoint::new(
+                    stringify!($name),
+                    alloc::vec![$(Parameter::new(stringify!($arg), <$ty as CLTyped>::cl_type())),*],
+                    contract_entry_points!(@ret_type $($ret)?),
+                    EntryPointAccess::Public,
+                    EntryPointType::Called,
+                ));
+            )*
+            entry_points
+        }
+
+        // 导出名即入口点名；置于私有模块，避免与 actions 的同名函数在 crate 根冲突
+        mod exports {
+            use super::*;
+
+            $(
+                #[no_mangle]
+                pub extern "C" fn $name() {
+                    $(let $arg: $ty = runtime::get_named_arg(stringify!($arg));)*
+                    contract_entry_points!(
+                        @dispatch
+                        (contract_entry_points!(@handler $name $($handler)?))($($arg),*)
+                        $(=> $ret)?
+                    );
+                }
+            )*
+        }
+    };
 }
 
-#[no_mangle]
-pub extern "C" fn burn_ceeth_for_eth_entry() {
-    let amount: U256 = runtime::get_named_arg("amount");
-    let tx_id: String = runtime::get_named_arg("tx_id");
-    let eth_owner: String = runtime::get_named_arg("eth_owner");
-    burn_ceeth_for_eth(amount, tx_id, eth_owner);
-}
+entry_point_registry!(contract_entry_points);
@@ -2,190 +2,538 @@
 extern crate alloc;
 
 use alloc::{string::String, vec::Vec};
+#[cfg(feature = "contract")]
 use casper_contract::contract_api::runtime;
+#[cfg(feature = "contract")]
+use casper_event_standard::{Event, EventInstance, Schemas};
+#[cfg(feature = "contract")]
+use casper_types::contract_messages::MessagePayload;
 use casper_types::{
-    bytesrepr::{Error, FromBytes, ToBytes},
-    contract_messages::MessagePayload,
-    CLTyped, Key, U256,
+    bytesrepr::{self, FromBytes, ToBytes},
+    Key, U256,
 };
 use casper_types_derive::{CLTyped, FromBytes, ToBytes};
 use hex;
 
-#[derive(Clone, Debug)]
-pub enum EventType {
-    Locked(u8),
-    UnlockRequested(u8),
-    UnlockFinalized(u8),
-    HotSwapProposed(u8),
-    HotSwapActivated(u8),
-    PauseChanged(u8),
-    YieldAccrued(u8),
-    CsprLockedForTarget(u8),
-    CsprLockedFromTarget(u8),
-    CeETHMinted(u8),
-    CeETHBurned(u8),
-}
+use crate::types::{ApprovalTier, AssetConfig, AssetRoute, ChainConfig};
+#[cfg(feature = "contract")]
+use crate::{
+    storage::{next_event_nonce, read_outbound_accumulator, write_outbound_accumulator},
+    utils::chain_outbound_root,
+};
 
-impl EventType {
-    pub const LOCKED: EventType = EventType::Locked(0);
-    pub const UNLOCK_REQUESTED: EventType = EventType::UnlockRequested(1);
-    pub const UNLOCK_FINALIZED: EventType = EventType::UnlockFinalized(2);
-    pub const HOT_SWAP_PROPOSED: EventType = EventType::HotSwapProposed(3);
-    pub const HOT_SWAP_ACTIVATED: EventType = EventType::HotSwapActivated(4);
-    pub const PAUSE_CHANGED: EventType = EventType::PauseChanged(5);
-    pub const YIELD_ACCRUED: EventType = EventType::YieldAccrued(6);
-    pub const CSPR_LOCKED_FOR_TARGET: EventType = EventType::CsprLockedForTarget(7);
-    pub const CSPR_LOCKED_FROM_TARGET: EventType = EventType::CsprLockedFromTarget(8);
-    pub const CEETH_MINTED: EventType = EventType::CeETHMinted(9);
-    pub const CEETH_BURNED: EventType = EventType::CeETHBurned(10);
-
-    fn serialized_length(&self) -> usize {
-        1
-    }
+/// 事件信封：LTEvents 消息载荷为其 ToBytes 的 hex
+///
+/// payload 带长度前缀，Relayer 遇到未知的判别值或版本时可整体跳过
+#[derive(Clone, Debug, PartialEq, Eq, CLTyped, ToBytes, FromBytes)]
+pub struct EventEnvelope {
+    pub nonce: u64,       // 全局事件序号
+    pub discriminant: u8, // 事件类型
+    pub version: u8,      // 事件 schema 版本
+    pub payload: Vec<u8>, // 事件字节（CES 编码）
 }
 
-impl CLTyped for EventType {
-    fn cl_type() -> casper_types::CLType {
-        casper_types::CLType::U8
+impl EventEnvelope {
+    /// 封装事件
+    pub fn seal<T: BridgeEvent>(nonce: u64, event: &T) -> Self {
+        Self {
+            nonce,
+            discriminant: T::DISCRIMINANT,
+            version: T::VERSION,
+            payload: event.to_bytes().unwrap(),
+        }
     }
-}
 
-impl ToBytes for EventType {
-    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
-        let value = match self {
-            EventType::Locked(v) => *v,
-            EventType::UnlockRequested(v) => *v,
-            EventType::UnlockFinalized(v) => *v,
-            EventType::HotSwapProposed(v) => *v,
-            EventType::HotSwapActivated(v) => *v,
-            EventType::PauseChanged(v) => *v,
-            EventType::YieldAccrued(v) => *v,
-            EventType::CsprLockedForTarget(v) => *v,
-            EventType::CsprLockedFromTarget(v) => *v,
-            EventType::CeETHMinted(v) => *v,
-            EventType::CeETHBurned(v) => *v,
-        };
-        value.to_bytes()
-    }
-
-    fn serialized_length(&self) -> usize {
-        1
+    /// 按指定事件类型解码；判别值或版本不匹配、payload 无法完整解码时返回 None
+    pub fn decode<T: BridgeEvent>(&self) -> Option<T> {
+        if self.discriminant != T::DISCRIMINANT || self.version != T::VERSION {
+            return None;
+        }
+        match T::from_bytes(&self.payload) {
+            Ok((event, rest)) if rest.is_empty() => Some(event),
+            _ => None,
+        }
     }
-}
 
-impl FromBytes for EventType {
-    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
-        let (value, rem) = u8::from_bytes(bytes)?;
-        let event_type = match value {
-            0 => EventType::Locked(0),
-            1 => EventType::UnlockRequested(1),
-            2 => EventType::UnlockFinalized(2),
-            3 => EventType::HotSwapProposed(3),
-            4 => EventType::HotSwapActivated(4),
-            5 => EventType::PauseChanged(5),
-            6 => EventType::YieldAccrued(6),
-            7 => EventType::CsprLockedForTarget(7),
-            8 => EventType::CsprLockedFromTarget(8),
-            9 => EventType::CeETHMinted(9),
-            10 => EventType::CeETHBurned(10),
-            _ => return Err(Error::Formatting),
-        };
-        Ok((event_type, rem))
+    /// 从 LTEvents 消息载荷（信封字节的 hex）还原信封
+    pub fn from_hex(message: &str) -> Result<Self, bytesrepr::Error> {
+        let bytes = hex::decode(message).map_err(|_| bytesrepr::Error::Formatting)?;
+        bytesrepr::deserialize(bytes)
     }
 }
 
-#[derive(Clone, Debug, CLTyped, ToBytes, FromBytes)]
-pub struct Locked {
-    pub sender: Key,
-    pub amount: U256,
-    pub dst_chain: String,
-    pub tx_id: String,
-    pub event_type: EventType,
+/// 可通过信封发出的事件
+pub trait BridgeEvent: ToBytes + FromBytes {
+    /// 事件类型判别值（一经发布不可复用）
+    const DISCRIMINANT: u8;
+    /// 事件 schema 版本，字段变化时递增
+    const VERSION: u8;
 }
 
-#[derive(Clone, Debug, CLTyped, ToBytes, FromBytes)]
-pub struct UnlockRequested {
-    pub request_id: String,
-    pub recipient: Key,
-    pub amount: U256,
-    pub src_chain: String,
-    pub dst_chain: String,
-    pub event_type: EventType,
-}
+/// 声明事件：`名称 => (判别值, 版本) { 字段 }`
+///
+/// 生成事件结构体及其 BridgeEvent 实现、按判别值解码的 AnyEvent 以及 CES schema 列表。
+/// 合约构建由 CES 派生字节编码；client 构建不依赖 casper-contract，按相同布局
+/// （`event_<名称>` 字符串 + 各字段）手工实现，两端字节一致
+macro_rules! bridge_events {
+    ($(
+        $name:ident => ($discriminant:literal, $version:literal) {
+            $($field:ident: $ty:ty),* $(,)?
+        }
+    )*) => {
+        $(
+            #[derive(Clone, Debug, PartialEq, Eq)]
+            #[cfg_attr(feature = "contract", derive(Event))]
+            pub struct $name {
+                $(pub $field: $ty,)*
+            }
 
-#[derive(Clone, Debug, CLTyped, ToBytes, FromBytes)]
-pub struct UnlockFinalized {
-    pub request_id: String,
-    pub recipient: Key,
-    pub amount: U256,
-    pub event_type: EventType,
-}
+            impl BridgeEvent for $name {
+                const DISCRIMINANT: u8 = $discriminant;
+                const VERSION: u8 = $version;
+            }
 
-#[derive(Clone, Debug, CLTyped, ToBytes, FromBytes)]
-pub struct HotSwapProposed {
-    pub patch_hash: String,
-    pub proposer: Key,
-    pub event_type: EventType,
-}
+            #[cfg(not(feature = "contract"))]
+            impl ToBytes for $name {
+                fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+                    let mut buffer = bytesrepr::allocate_buffer(self)?;
+                    buffer.extend(String::from(concat!("event_", stringify!($name))).to_bytes()?);
+                    $(buffer.extend(self.$field.to_bytes()?);)*
+                    Ok(buffer)
+                }
 
-#[derive(Clone, Debug, CLTyped, ToBytes, FromBytes)]
-pub struct HotSwapActivated {
-    pub patch_hash: String,
-    pub event_type: EventType,
-}
+                fn serialized_length(&self) -> usize {
+                    String::from(concat!("event_", stringify!($name))).serialized_length()
+                        $(+ self.$field.serialized_length())*
+                }
+            }
 
-#[derive(Clone, Debug, CLTyped, ToBytes, FromBytes)]
-pub struct PauseChanged {
-    pub paused: bool,
-    pub event_type: EventType,
-}
+            #[cfg(not(feature = "contract"))]
+            impl FromBytes for $name {
+                fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+                    let (event_name, remainder) = String::from_bytes(bytes)?;
+                    if event_name != concat!("event_", stringify!($name)) {
+                        return Err(bytesrepr::Error::Formatting);
+                    }
+                    $(let ($field, remainder) = <$ty>::from_bytes(remainder)?;)*
+                    Ok((Self { $($field,)* }, remainder))
+                }
+            }
+        )*
 
-#[derive(Clone, Debug, CLTyped, ToBytes, FromBytes)]
-pub struct YieldAccrued {
-    pub account: Key,
-    pub principal_after: U256,
-    pub event_type: EventType,
-}
+        /// 已登记的全部事件
+        #[derive(Clone, Debug, PartialEq)]
+        pub enum AnyEvent {
+            $($name($name),)*
+        }
 
-//for cspr to eth bridge only (for now we only support cspr to eth bridge)
-#[derive(Clone, Debug, CLTyped, ToBytes, FromBytes)]
-pub struct CsprLockedForTarget {
-    pub sender: Key,
-    pub amount: U256,
-    pub dst_chain: String,
-    pub tx_id: String,
-    pub recipient: String, // stores recipient address on target chain(currently only eth)
-    pub event_type: EventType,
-}
+        $(
+            impl From<$name> for AnyEvent {
+                fn from(event: $name) -> Self {
+                    AnyEvent::$name(event)
+                }
+            }
+        )*
+
+        impl AnyEvent {
+            /// 解码信封；判别值未知或版本不受支持时返回 None，调用方可直接跳过
+            pub fn open(envelope: &EventEnvelope) -> Option<AnyEvent> {
+                match envelope.discriminant {
+                    $($discriminant => envelope.decode::<$name>().map(AnyEvent::$name),)*
+                    _ => None,
+                }
+            }
+
+            /// 直接解码 LTEvents 消息载荷（hex 字符串）
+            pub fn from_message(message: &str) -> Option<AnyEvent> {
+                EventEnvelope::from_hex(message).ok().as_ref().and_then(AnyEvent::open)
+            }
+        }
 
-#[derive(Clone, Debug, CLTyped, ToBytes, FromBytes)]
-pub struct CsprLockedFromTarget {
-    pub recipient: Key,
-    pub amount: U256,
-    pub src_chain: String, // stores source chain(currently only eth)
-    pub tx_id: String,
-    pub event_type: EventType,
+        /// 事件 schema（Casper Event Standard），安装时写入 __events_schema，浏览器与 SDK 据此解码
+        #[cfg(feature = "contract")]
+        pub fn schemas() -> Schemas {
+            Schemas::new()$(.with::<$name>())*
+        }
+    };
 }
 
-#[derive(Clone, Debug, CLTyped, ToBytes, FromBytes)]
-pub struct CeETHMinted {
-    pub recipient: Key,
-    pub amount: U256,
-    pub tx_id: String,
-    pub event_type: EventType,
+bridge_events! {
+    UnlockRequested => (0, 1) {
+        request_id: String,
+        recipient: Key,
+        amount: U256,        // 本链精度
+        remote_amount: U256, // 源链事件中的原始金额
+        asset: String,
+        src_chain: String,
+        src_tx_hash: String,
+        log_index: u64,
+        dst_chain: String,
+        relayer_tip: U256,
+        submitter: Key,
+        required_weight: u32, // 按金额档位确定的审批权重
+    }
+
+    UnlockFinalized => (1, 1) {
+        request_id: String,
+        recipient: Key,
+        asset: String,
+        amount: U256, // 扣除手续费与小费后的到账金额
+        fee: U256,
+        relayer_tip: U256, // 支付给 submitter 的小费
+        submitter: Key,
+    }
+
+    HotSwapProposed => (2, 1) {
+        patch_hash: String,
+        proposer: Key,
+    }
+
+    HotSwapActivated => (3, 1) {
+        patch_hash: String,
+    }
+
+    PauseChanged => (4, 1) {
+        paused: bool,
+    }
+
+    YieldAccrued => (5, 1) {
+        account: Key,
+        principal_after: U256,
+        gross_interest: U256, // 本次计提的总利息
+        fee: U256,            // 协议业绩分成
+        net_interest: U256,   // 计入本金的净利息
+    }
+
+    //for cspr to eth bridge only (for now we only support cspr to eth bridge)
+    CsprLockedForTarget => (6, 1) {
+        sender: Key,
+        amount: U256,
+        dst_chain: String,
+        tx_id: String,
+        recipient: Vec<u8>, // normalised recipient address bytes on target chain (20 bytes for evm)
+        fee: U256,          // amount is net of fee and relayer_tip
+        relayer_tip: U256,  // paid to the relayer delivering on the target chain, on top of amount
+        remote_amount: U256, // amount in target chain decimals
+        remote_relayer_tip: U256, // relayer_tip in target chain decimals
+    }
+
+    CeETHMinted => (7, 1) {
+        recipient: Key,
+        asset: String, // 始终为 ceETH，其他 mint/burn 资产使用 AssetMinted
+        amount: U256,  // 扣除手续费与小费后的铸造金额
+        fee: U256,
+        relayer_tip: U256, // 铸造给 submitter 的小费
+        submitter: Key,
+        tx_id: String,
+    }
+
+    CeETHBurned => (8, 1) {
+        eth_owner: Vec<u8>, // 规范化后的 20 字节 ETH 地址
+        dst_chain: String,
+        amount: U256,
+        remote_amount: U256, // 目标链精度
+        tx_id: String,
+    }
+
+    RewardReserveFunded => (9, 1) {
+        funder: Key,
+        amount: U256,
+        reserve_after: U256,
+    }
+
+    // 储备低于告警水位或无法覆盖本次利息时触发
+    RewardReserveLow => (10, 1) {
+        reserve: U256,
+        watermark: U256,
+        uncovered_interest: U256, // 因储备不足而未能计提的利息
+    }
+
+    FeesWithdrawn => (11, 1) {
+        asset: String,
+        recipient: Key,
+        amount: U256,
+    }
+
+    RelayerRegistered => (12, 1) {
+        relayer: Key,
+        bond: U256, // 注册/追加后的保证金总额
+    }
+
+    RelayerUnbonding => (13, 1) {
+        relayer: Key,
+        bond: U256,
+        unbonding_at_ms: u64,
+    }
+
+    RelayerSlashed => (14, 1) {
+        relayer: Key,
+        request_id: String, // 被判定为伪造/被拒绝的请求
+        amount: U256,       // 罚没的保证金，转入协议金库
+    }
+
+    // 已被拒绝的请求被以不同内容重新提交并替换
+    RequestConflict => (15, 1) {
+        request_id: String,
+        existing_digest: String,
+        proposed_digest: String,
+        submitter: Key, // 提交冲突内容的 Relayer
+    }
+
+    // 通用资产出站：锁定在 Bridge 托管，等待目标链释放/铸造
+    AssetLocked => (16, 1) {
+        asset: String,
+        sender: Key,
+        dst_chain: String,
+        recipient: Vec<u8>,    // 规范化后的目标链地址
+        remote_token: Vec<u8>, // 目标链上对应的代币地址
+        amount: U256,          // 扣除手续费与小费后的到账金额（本链精度）
+        fee: U256,
+        relayer_tip: U256,   // 目标链上额外支付给 Relayer
+        remote_amount: U256, // 到账金额（目标链精度）
+        remote_relayer_tip: U256,
+        tx_id: String,
+    }
+
+    // 通用资产出站：包装资产在本链销毁，目标链释放原生资产
+    AssetBurned => (17, 1) {
+        asset: String,
+        sender: Key,
+        dst_chain: String,
+        recipient: Vec<u8>,
+        remote_token: Vec<u8>,
+        amount: U256,
+        remote_amount: U256,
+        tx_id: String,
+    }
+
+    // 请求已达审批阈值，但超出限流额度或属于大额转账，转入待释放队列
+    ReleaseQueued => (18, 1) {
+        request_id: String,
+        src_chain: String,
+        asset: String,
+        amount: U256,
+        remaining_capacity: U256, // 排队时该链/资产剩余的限流额度
+        release_after_ms: u64,    // 最早可执行 execute_release 的时间
+    }
+
+    // 拒绝权重达到阈值，排队中的请求被移出队列
+    ReleaseCancelled => (19, 1) {
+        request_id: String,
+        guardian: Key, // 投出达到阈值一票的守护节点
+    }
+
+    // 合约初始化完成
+    BridgeInitialized => (20, 1) {
+        admin: Key,
+        guardian_count: u32,
+        threshold: u32,
+        base_apr_bps: u32,
+    }
+
+    // 守护节点的单次投票（解锁、铸造、热修复、罚没）
+    GuardianVoted => (21, 1) {
+        subject: String, // request_id / patch_hash / 被罚没的 Relayer 请求
+        kind: u8,        // VOTE_KIND_*
+        guardian: Key,
+        weight: u32,
+        approvals_weight: u32, // 投票后的累计权重
+        required_weight: u32,
+    }
+
+    AprUpdated => (22, 1) {
+        old_apr_bps: u32,
+        new_apr_bps: u32,
+    }
+
+    AdminTransferred => (23, 1) {
+        old_admin: Key,
+        new_admin: Key,
+    }
+
+    TreasurerChanged => (24, 1) {
+        old_treasurer: Key,
+        new_treasurer: Key,
+    }
+
+    FeeBpsUpdated => (25, 1) {
+        old_fee_bps: u32,
+        new_fee_bps: u32,
+    }
+
+    PerformanceFeeBpsUpdated => (26, 1) {
+        old_fee_bps: u32,
+        new_fee_bps: u32,
+    }
+
+    RouteFeeLimitsUpdated => (27, 1) {
+        asset: String,
+        chain: String,
+        old_min_fee: U256,
+        old_max_fee: U256,
+        new_min_fee: U256,
+        new_max_fee: U256,
+    }
+
+    ReserveWatermarkUpdated => (28, 1) {
+        old_watermark: U256,
+        new_watermark: U256,
+    }
+
+    RelayerBondConfigUpdated => (29, 1) {
+        old_min_bond: U256,
+        new_min_bond: U256,
+        old_unbonding_ms: u64,
+        new_unbonding_ms: u64,
+    }
+
+    RelayerBondWithdrawn => (30, 1) {
+        relayer: Key,
+        amount: U256,
+    }
+
+    // 链注册表条目新增或修改（old 为 None 表示新增）
+    ChainUpdated => (31, 1) {
+        id: String,
+        old: Option<ChainConfig>,
+        new: ChainConfig,
+    }
+
+    // 资产注册表条目新增或修改（old 为 None 表示新增）
+    AssetUpdated => (32, 1) {
+        id: String,
+        old: Option<AssetConfig>,
+        new: AssetConfig,
+    }
+
+    AssetRouteUpdated => (33, 1) {
+        asset: String,
+        chain: String,
+        old: Option<AssetRoute>,
+        new: AssetRoute,
+    }
+
+    RateLimitUpdated => (34, 1) {
+        chain: String,
+        asset: String,
+        old_cap: U256,
+        new_cap: U256,
+        old_window_ms: u64,
+        new_window_ms: u64,
+    }
+
+    ReleaseDelayUpdated => (35, 1) {
+        asset: String,
+        old_amount_threshold: U256,
+        new_amount_threshold: U256,
+        old_delay_ms: u64,
+        new_delay_ms: u64,
+    }
+
+    ApprovalTiersUpdated => (36, 1) {
+        asset: String,
+        old: Vec<ApprovalTier>,
+        new: Vec<ApprovalTier>,
+    }
+
+    MintAuthorizationChanged => (37, 1) {
+        asset: String,
+        old_authorized: bool,
+        new_authorized: bool,
+    }
+
+    CeETHTokenSet => (38, 1) {
+        old_token: Option<Key>,
+        new_token: Key,
+    }
+
+    // mint/burn 资产的入站铸造请求
+    MintRequested => (39, 1) {
+        request_id: String,
+        recipient: Key,
+        amount: U256,        // 本链精度
+        remote_amount: U256, // 源链事件中的原始金额
+        asset: String,
+        src_chain: String,
+        src_tx_hash: String,
+        log_index: u64,
+        dst_chain: String,
+        relayer_tip: U256,
+        submitter: Key,
+        required_weight: u32,
+    }
+
+    PositionWithdrawn => (40, 1) {
+        account: Key,
+        amount: U256,
+        principal_after: U256,
+    }
+
+    MaxRelayerTipBpsUpdated => (41, 1) {
+        old_tip_bps: u32,
+        new_tip_bps: u32,
+    }
+
+    // 守护节点拒绝权重达到阈值，请求作废，可用正确内容重新提交
+    RequestRejected => (42, 1) {
+        request_id: String,
+        asset: String,
+        payload_digest: String, // 被拒绝的请求内容
+        submitter: Key,         // 提交该内容的 Relayer，可据此罚没
+        rejections_weight: u32,
+    }
+
+    // 通用入站：ceETH 以外的 mint/burn 资产完成铸造
+    AssetMinted => (43, 1) {
+        asset: String,
+        recipient: Key,
+        amount: U256, // 扣除手续费与小费后的铸造金额
+        fee: U256,
+        relayer_tip: U256, // 铸造给 submitter 的小费
+        submitter: Key,
+        request_id: String,
+    }
+
+    // treasurer 从协议储备（利息业绩分成）中提取 CSPR
+    ProtocolReserveWithdrawn => (44, 1) {
+        recipient: Key,
+        amount: U256,
+        reserve_after: U256,
+    }
 }
 
-#[derive(Clone, Debug, CLTyped, ToBytes, FromBytes)]
-pub struct CeETHBurned {
-    pub eth_owner: String,
-    pub amount: U256,
-    pub tx_id: String,
-    pub event_type: EventType,
+/// 初始化 CES 事件存储（__events、__events_length、__events_schema、__events_ces_version）
+///
+/// 命名键写入当前上下文，需在合约自身的 init 中调用
+#[cfg(feature = "contract")]
+pub fn init_events() {
+    casper_event_standard::init(schemas());
 }
 
-pub fn emit<T: CLTyped + ToBytes + FromBytes>(event: T) {
-    let bytes = event.to_bytes().unwrap();
-    let hex_string = hex::encode(bytes);
-    let message = MessagePayload::from(hex_string);
+/// 触发事件并返回其全局序号
+///
+/// 事件写入 CES 的 __events 字典（下标与序号一致），
+/// 同时将 EventEnvelope 以 hex 发到 LTEvents 消息主题，Relayer 可据序号发现遗漏的事件
+#[cfg(feature = "contract")]
+pub fn emit<T: BridgeEvent + EventInstance>(event: T) -> u64 {
+    let nonce = next_event_nonce();
+    let envelope = EventEnvelope::seal(nonce, &event);
+    casper_event_standard::emit(event);
+    let message = MessagePayload::from(hex::encode(envelope.to_bytes().unwrap()));
     runtime::emit_message("LTEvents", &message);
+    nonce
+}
+
+/// 触发出站事件，并将其追加到出站哈希链
+///
+/// Relayer 在目标链按序重放链头，即可证明投递的出站事件完整且无缺口
+#[cfg(feature = "contract")]
+pub fn emit_outbound<T: BridgeEvent + EventInstance>(event: T) {
+    let payload = event.to_bytes().unwrap();
+    let nonce = emit(event);
+    let mut accumulator = read_outbound_accumulator();
+    accumulator.root = chain_outbound_root(&accumulator.root, nonce, &payload);
+    accumulator.count += 1;
+    accumulator.last_nonce = nonce;
+    write_outbound_accumulator(accumulator);
 }
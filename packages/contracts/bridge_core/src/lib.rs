@@ -1,18 +1,47 @@
-#![no_std]
-#![no_main]
+//! 跨链 Bridge 合约
+//!
+//! 默认（contract 特性）构建为 Casper wasm 合约。链下程序（Relayer、SDK）应以
+//! `default-features = false, features = ["client"]` 依赖本 crate，只获得类型、事件解码与
+//! `args` 中的 RuntimeArgs 构造函数，而不会链接 casper-contract 的 wasm 宿主函数：
+//!
+//! ```toml
+//! bridge_core = { path = "...", default-features = false, features = ["client"] }
+//! ```
+#![cfg_attr(not(feature = "client"), no_std)]
+#![cfg_attr(feature = "contract", no_main)]
 
 extern crate alloc;
 
+#[macro_use]
+mod registry;
+
+#[cfg(feature = "contract")]
 mod actions;
+#[cfg(feature = "contract")]
+mod adapter;
+mod address;
+#[cfg(feature = "contract")]
 mod entrypoints;
 mod events;
+#[cfg(feature = "contract")]
 mod storage;
 mod types;
 mod utils;
 
+/// 客户端按入口点构造 RuntimeArgs
+#[cfg(feature = "client")]
+pub mod args;
+
+#[cfg(feature = "contract")]
 pub use actions::*;
+#[cfg(feature = "contract")]
+pub use adapter::*;
+pub use address::*;
+#[cfg(feature = "contract")]
 pub use entrypoints::*;
 pub use events::*;
+pub use registry::*;
+#[cfg(feature = "contract")]
 pub use storage::*;
 pub use types::*;
 pub use utils::*;
@@ -0,0 +1,145 @@
+//! 入口点登记表：合约入口点的名称、参数与返回类型只在此声明一次
+//!
+//! 合约构建据此生成 EntryPoint 定义、`#[no_mangle]` 导出函数与参数解析，
+//! client 构建据此生成 RuntimeArgs 构造函数，三者不会再各自维护而失配。
+
+/// 以登记表调用 `$generator!`
+///
+/// 每项格式为 `名称(参数: 类型, ...) [-> 返回类型] [=> 处理函数];`，
+/// 处理函数缺省为 `actions` 中的同名函数；无返回类型的入口点返回 Unit。
+macro_rules! entry_point_registry {
+    ($generator:ident) => {
+        $generator! {
+            init(admin: Key, guardians: Vec<Guardian>, threshold: u32, base_apr_bps: u32);
+            create_unlock_request(
+                src_chain: String,
+                src_tx_hash: String,
+                log_index: u64,
+                recipient: Key,
+                amount: U256,
+                asset: String,
+                dst_chain: String,
+                relayer_tip: Option<U256>,
+            );
+            approve_unlock(request_id: String, payload_digest: String);
+            reject_unlock(request_id: String, payload_digest: String);
+            propose_hot_swap(patch_hash: String);
+            approve_hot_swap(patch_hash: String);
+            set_pause(paused: bool);
+            update_apr(new_apr_bps: u32);
+            transfer_admin(new_admin: Key);
+            get_position(account: Key) -> VaultPosition;
+            accrue();
+            withdraw_position(amount: U256);
+            set_ceeth_token(token: Key) => set_ceeth_token_entry;
+            lock_cspr_for_eth(
+                amount: U256,
+                tx_id: String,
+                dst_chain: String,
+                recipient: String,
+                relayer_tip: Option<U256>,
+            );
+            create_ceeth_mint_request(
+                src_chain: String,
+                src_tx_hash: String,
+                log_index: u64,
+                recipient: Key,
+                amount: U256,
+                asset: String,
+                dst_chain: String,
+                relayer_tip: Option<U256>,
+            );
+            approve_ceeth_mint(request_id: String, payload_digest: String);
+            approve_mint(asset: String, request_id: String, payload_digest: String);
+            reject_mint(asset: String, request_id: String, payload_digest: String);
+            burn_ceeth_for_eth(amount: U256, tx_id: String, dst_chain: String, eth_owner: String);
+            fund_reward_reserve(amount: U256);
+            set_reserve_low_watermark(amount: U256);
+            get_solvency() -> SolvencyReport;
+            set_fee_bps(fee_bps: u32);
+            set_performance_fee_bps(fee_bps: u32);
+            set_max_relayer_tip_bps(tip_bps: u32);
+            set_route_fee_limits(asset: String, chain: String, min_fee: U256, max_fee: U256);
+            set_treasurer(treasurer: Key) => set_treasurer_entry;
+            withdraw_fees(asset: String, amount: U256, recipient: Key);
+            get_treasury_balance(asset: String) -> U256;
+            withdraw_protocol_reserve(amount: U256, recipient: Key);
+            set_relayer_bond_config(min_bond: U256, unbonding_ms: u64);
+            register_relayer(bond: U256);
+            deregister_relayer();
+            withdraw_relayer_bond();
+            slash_relayer(relayer: Key, request_id: String);
+            get_relayer(relayer: Key) -> RelayerInfo;
+            set_chain(
+                id: String,
+                evm_chain_id: u64,
+                address_format: u8,
+                decimals: u8,
+                enabled: bool,
+            );
+            get_chain(id: String) -> ChainConfig;
+            get_supported_chains() -> Vec<String>;
+            register_asset(id: String, token: Option<Key>, decimals: u8, mode: u8, enabled: bool);
+            set_asset_route(
+                asset: String,
+                chain: String,
+                remote_token: String,
+                remote_decimals: u8,
+                enabled: bool,
+                min_amount: U256,
+                max_amount: U256,
+            );
+            get_asset(id: String) -> AssetConfig;
+            get_asset_route(asset: String, chain: String) -> AssetRoute;
+            get_supported_assets() -> Vec<String>;
+            lock_asset(
+                asset: String,
+                amount: U256,
+                tx_id: String,
+                dst_chain: String,
+                recipient: String,
+                relayer_tip: Option<U256>,
+            );
+            create_mint_request(
+                src_chain: String,
+                src_tx_hash: String,
+                log_index: u64,
+                recipient: Key,
+                amount: U256,
+                asset: String,
+                dst_chain: String,
+                relayer_tip: Option<U256>,
+            );
+            burn_asset(
+                asset: String,
+                amount: U256,
+                tx_id: String,
+                dst_chain: String,
+                recipient: String,
+            );
+            set_rate_limit(chain: String, asset: String, cap: U256, window_ms: u64);
+            get_rate_limit(chain: String, asset: String) -> RateLimit;
+            get_remaining_capacity(chain: String, asset: String) -> U256;
+            execute_release(request_id: String);
+            set_approval_tiers(asset: String, tiers: Vec<ApprovalTier>);
+            get_approval_tiers(asset: String) -> Vec<ApprovalTier>;
+            cancel_release(request_id: String);
+            get_pending_release(request_id: String) -> PendingRelease;
+            set_release_delay(asset: String, amount_threshold: U256, delay_ms: u64);
+            get_release_delay(asset: String) -> ReleaseDelay;
+            set_mint_authorized(asset: String, authorized: bool);
+            get_reserves(asset: String) -> AssetReserves;
+            assert_invariants(asset: String);
+            get_outbound_accumulator() -> OutboundAccumulator;
+        }
+    };
+}
+
+/// 登记的全部入口点名称（不含安装会话的 call）
+macro_rules! entry_point_names {
+    ($($name:ident($($arg:ident: $ty:ty),* $(,)?) $(-> $ret:ty)? $(=> $handler:ident)?;)*) => {
+        pub const ENTRY_POINT_NAMES: &[&str] = &[$(stringify!($name)),*];
+    };
+}
+
+entry_point_registry!(entry_point_names);
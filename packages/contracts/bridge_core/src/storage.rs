@@ -1,31 +1,73 @@
-use crate::types::{BridgeError, Guardian};
+use crate::{
+    types::{
+        ApprovalTier, AssetConfig, AssetReserves, AssetRoute, BridgeError, ChainConfig, Guardian,
+        OutboundAccumulator, PendingRelease, RateLimit, RelayerInfo, ReleaseDelay,
+    },
+    utils::push_field,
+};
 use alloc::{string::String, vec::Vec};
 use casper_contract::{
-    contract_api::{runtime, storage},
+    contract_api::{runtime, storage, system},
     unwrap_or_revert::UnwrapOrRevert,
 };
 use casper_types::{
     bytesrepr::{FromBytes, ToBytes},
-    CLTyped, Key, URef,
+    crypto::blake2b,
+    CLTyped, Key, URef, U256,
 };
 
 // ==========================================
 // 1. 常量定义 (数据库表名)
 // ==========================================
-pub const DICT_PROCESSED_TXS: &str = "processed_txs"; // 记录已处理交易 (防重放)
+pub const DICT_OUTBOUND_TXS: &str = "outbound_txs"; // 已使用的出站 tx_id (防重放)
+pub const DICT_INBOUND_REQUESTS: &str = "inbound_requests"; // 已完成的入站请求 ID (防重放)
 pub const DICT_BALANCES: &str = "vault_balances"; // 用户生息金库
 pub const DICT_UNLOCK_REQS: &str = "unlock_requests"; // 解锁请求
 pub const DICT_GUARDIANS: &str = "guardian_weights"; // 守护节点权重 用于投票 （守护节点是多个节点组成的，每个节点有不同的权重）
 pub const DICT_HOTSWAP: &str = "hotswap_patches";
-pub const DICT_CEETH_MINT_REQS: &str = "ceeth_mint_requests";
+pub const DICT_MINT_REQS: &str = "mint_requests"; // mint/burn 资产的铸造请求（按资产）
 pub const DICT_HOTSWAP_VOTES: &str = "hotswap_votes";
 pub const DICT_UNLOCK_VOTES: &str = "unlock_votes";
+pub const DICT_MINT_VOTES: &str = "mint_votes";
+pub const DICT_FEE_TREASURY: &str = "fee_treasury"; // 按资产累计的协议手续费
+pub const DICT_RELAYERS: &str = "relayers"; // Relayer 注册表与保证金
+pub const DICT_RELAYER_SLASHES: &str = "relayer_slashes"; // 罚没投票累计权重
+pub const DICT_RELAYER_SLASH_VOTES: &str = "relayer_slash_votes";
+pub const DICT_REJECT_VOTES: &str = "reject_votes"; // 拒绝投票（按请求内容摘要）
+pub const DICT_REJECTED_SUBMISSIONS: &str = "rejected_submissions"; // 被拒绝的提交（罚没依据）
+pub const DICT_CHAINS: &str = "chains"; // 链注册表
+pub const DICT_ASSETS: &str = "assets"; // 资产注册表
+pub const DICT_ASSET_ROUTES: &str = "asset_routes"; // 资产 -> 远端代币映射（按链）
+pub const DICT_RATE_LIMITS: &str = "rate_limits"; // 入站释放限流（按来源链、资产）
+pub const DICT_PENDING_RELEASES: &str = "pending_releases"; // 超出限流排队的请求
+pub const DICT_APPROVAL_TIERS: &str = "approval_tiers"; // 按资产的金额审批档位
+pub const DICT_RELEASE_DELAYS: &str = "release_delays"; // 按资产的大额延迟释放配置
+pub const DICT_MINT_AUTHORIZATIONS: &str = "mint_authorizations"; // Bridge 是否持有代币铸造权限
+pub const DICT_RESERVES: &str = "asset_reserves"; // 按资产的供应量计数
+pub const DICT_SOURCE_EVENTS: &str = "source_events"; // 源链事件 -> 占用它的入站请求 ID
 pub const KEY_ADMIN: &str = "admin"; // 管理员 Key
 pub const KEY_THRESHOLD: &str = "threshold";
+pub const KEY_GUARDIAN_TOTAL_WEIGHT: &str = "guardian_total_weight"; // 全部守护节点的权重之和
 pub const KEY_BASE_APR_BPS: &str = "base_apr_bps";
 pub const KEY_PAUSED: &str = "paused";
 pub const KEY_ACTIVE_PATCH: &str = "active_patch";
 pub const KEY_CEETH_TOKEN: &str = "ceeth_token";
+pub const KEY_BRIDGE_PURSE: &str = "bridge_purse"; // 合约自有资金池
+pub const KEY_TOTAL_PRINCIPAL: &str = "total_principal"; // 全局本金总额
+pub const KEY_TOTAL_ACCRUED_INTEREST: &str = "total_accrued_interest"; // 全局已计提利息
+pub const KEY_REWARD_RESERVE: &str = "reward_reserve"; // 已注资、尚未分配的利息储备
+pub const KEY_RESERVE_LOW_WATERMARK: &str = "reserve_low_watermark"; // 储备告警水位
+pub const KEY_FEE_BPS: &str = "fee_bps"; // 跨链手续费率 (bps)
+pub const KEY_TREASURER: &str = "treasurer"; // 手续费提取权限
+pub const KEY_PERFORMANCE_FEE_BPS: &str = "performance_fee_bps"; // 利息业绩分成 (bps)
+pub const KEY_MAX_RELAYER_TIP_BPS: &str = "max_relayer_tip_bps"; // Relayer 小费上限 (bps)
+pub const KEY_PROTOCOL_RESERVE: &str = "protocol_reserve"; // 业绩分成累计
+pub const KEY_MIN_RELAYER_BOND: &str = "min_relayer_bond"; // Relayer 最低保证金
+pub const KEY_RELAYER_UNBONDING_MS: &str = "relayer_unbonding_ms"; // 保证金退出等待期
+pub const KEY_CHAIN_IDS: &str = "chain_ids"; // 已注册链 ID 列表（用于查询）
+pub const KEY_ASSET_IDS: &str = "asset_ids"; // 已注册资产 ID 列表（用于查询）
+pub const KEY_EVENT_NONCE: &str = "event_nonce"; // 下一个事件的全局序号
+pub const KEY_OUTBOUND_ACCUMULATOR: &str = "outbound_accumulator"; // 出站事件哈希链
 
 // ==========================================
 // 2. 核心工具函数 (Generic Helpers)
@@ -41,11 +83,156 @@ fn get_uref(name: &str) -> URef {
         .unwrap_or_revert()
 }
 
+/// 字典记录的类型化 Key
+///
+/// 每种记录对应自己的字典；条目键为各组成部分的 blake2b 哈希（64 个 hex 字符），
+/// 不受 request_id、Key 格式化字符串等长度影响，始终满足字典条目键的长度限制。
+#[derive(Clone, Copy, Debug)]
+pub enum StorageKey<'a> {
+    OutboundTx(&'a str),
+    InboundRequest(&'a str),
+    Balance(&'a Key),
+    Guardian(&'a Key),
+    UnlockRequest(&'a str),
+    UnlockVote(&'a str, &'a Key),
+    MintRequest(&'a str, &'a str),
+    MintVote(&'a str, &'a Key),
+    HotSwapPatch(&'a str),
+    HotSwapVote(&'a str, &'a Key),
+    FeeTreasury(&'a str),
+    Relayer(&'a Key),
+    RelayerSlash(&'a Key, &'a str),
+    RelayerSlashVote(&'a Key, &'a str, &'a Key),
+    RejectVote(&'a str, &'a Key),
+    RejectedSubmission(&'a str, &'a Key),
+    Chain(&'a str),
+    Asset(&'a str),
+    AssetRoute(&'a str, &'a str),
+    RateLimit(&'a str, &'a str),
+    PendingRelease(&'a str),
+    ApprovalTiers(&'a str),
+    ReleaseDelay(&'a str),
+    MintAuthorization(&'a str),
+    Reserves(&'a str),
+    SourceEvent(&'a str, &'a str, u64),
+}
+
+impl StorageKey<'_> {
+    /// 记录所在的字典
+    pub fn dictionary(&self) -> &'static str {
+        match self {
+            StorageKey::OutboundTx(_) => DICT_OUTBOUND_TXS,
+            StorageKey::InboundRequest(_) => DICT_INBOUND_REQUESTS,
+            StorageKey::Balance(_) => DICT_BALANCES,
+            StorageKey::Guardian(_) => DICT_GUARDIANS,
+            StorageKey::UnlockRequest(_) => DICT_UNLOCK_REQS,
+            StorageKey::UnlockVote(..) => DICT_UNLOCK_VOTES,
+            StorageKey::MintRequest(..) => DICT_MINT_REQS,
+            StorageKey::MintVote(..) => DICT_MINT_VOTES,
+            StorageKey::HotSwapPatch(_) => DICT_HOTSWAP,
+            StorageKey::HotSwapVote(..) => DICT_HOTSWAP_VOTES,
+            StorageKey::FeeTreasury(_) => DICT_FEE_TREASURY,
+            StorageKey::Relayer(_) => DICT_RELAYERS,
+            StorageKey::RelayerSlash(..) => DICT_RELAYER_SLASHES,
+            StorageKey::RelayerSlashVote(..) => DICT_RELAYER_SLASH_VOTES,
+            StorageKey::RejectVote(..) => DICT_REJECT_VOTES,
+            StorageKey::RejectedSubmission(..) => DICT_REJECTED_SUBMISSIONS,
+            StorageKey::Chain(_) => DICT_CHAINS,
+            StorageKey::Asset(_) => DICT_ASSETS,
+            StorageKey::AssetRoute(..) => DICT_ASSET_ROUTES,
+            StorageKey::RateLimit(..) => DICT_RATE_LIMITS,
+            StorageKey::PendingRelease(_) => DICT_PENDING_RELEASES,
+            StorageKey::ApprovalTiers(_) => DICT_APPROVAL_TIERS,
+            StorageKey::ReleaseDelay(_) => DICT_RELEASE_DELAYS,
+            StorageKey::MintAuthorization(_) => DICT_MINT_AUTHORIZATIONS,
+            StorageKey::Reserves(_) => DICT_RESERVES,
+            StorageKey::SourceEvent(..) => DICT_SOURCE_EVENTS,
+        }
+    }
+
+    /// 字典条目键：blake2b(各组成部分) 的 hex 编码
+    pub fn item_key(&self) -> String {
+        let mut preimage = Vec::new();
+        match self {
+            StorageKey::OutboundTx(id)
+            | StorageKey::InboundRequest(id)
+            | StorageKey::UnlockRequest(id)
+            | StorageKey::HotSwapPatch(id)
+            | StorageKey::FeeTreasury(id)
+            | StorageKey::Chain(id)
+            | StorageKey::Asset(id)
+            | StorageKey::PendingRelease(id)
+            | StorageKey::ApprovalTiers(id)
+            | StorageKey::ReleaseDelay(id)
+            | StorageKey::MintAuthorization(id)
+            | StorageKey::Reserves(id) => push_field(&mut preimage, id.as_bytes()),
+            StorageKey::Balance(account)
+            | StorageKey::Guardian(account)
+            | StorageKey::Relayer(account) => push_key(&mut preimage, account),
+            StorageKey::UnlockVote(id, voter)
+            | StorageKey::MintVote(id, voter)
+            | StorageKey::HotSwapVote(id, voter)
+            | StorageKey::RejectVote(id, voter)
+            | StorageKey::RejectedSubmission(id, voter) => {
+                push_field(&mut preimage, id.as_bytes());
+                push_key(&mut preimage, voter);
+            }
+            StorageKey::MintRequest(asset, id) => {
+                push_field(&mut preimage, asset.as_bytes());
+                push_field(&mut preimage, id.as_bytes());
+            }
+            StorageKey::AssetRoute(asset, chain) => {
+                push_field(&mut preimage, asset.as_bytes());
+                push_field(&mut preimage, chain.as_bytes());
+            }
+            StorageKey::RateLimit(chain, asset) => {
+                push_field(&mut preimage, chain.as_bytes());
+                push_field(&mut preimage, asset.as_bytes());
+            }
+            StorageKey::RelayerSlash(relayer, request_id) => {
+                push_key(&mut preimage, relayer);
+                push_field(&mut preimage, request_id.as_bytes());
+            }
+            StorageKey::RelayerSlashVote(relayer, request_id, voter) => {
+                push_key(&mut preimage, relayer);
+                push_field(&mut preimage, request_id.as_bytes());
+                push_key(&mut preimage, voter);
+            }
+            StorageKey::SourceEvent(chain, tx_hash, log_index) => {
+                push_field(&mut preimage, chain.as_bytes());
+                push_field(&mut preimage, tx_hash.as_bytes());
+                push_field(&mut preimage, &log_index.to_le_bytes());
+            }
+        }
+        hex::encode(blake2b(preimage))
+    }
+}
+
+fn push_key(preimage: &mut Vec<u8>, key: &Key) {
+    push_field(preimage, &key.to_bytes().unwrap_or_revert());
+}
+
+/// 读取类型化记录
+pub fn read_record<T: CLTyped + FromBytes>(key: StorageKey) -> Option<T> {
+    read_dictionary_value(key.dictionary(), &key.item_key())
+}
+
+/// 写入类型化记录
+pub fn write_record<T: CLTyped + ToBytes>(key: StorageKey, value: T) {
+    write_dictionary_value(key.dictionary(), &key.item_key(), value);
+}
+
+/// 记录一次投票，已投过票时返回 false
+pub fn record_vote(key: StorageKey) -> bool {
+    if read_record::<bool>(key).unwrap_or(false) {
+        return false;
+    }
+    write_record(key, true);
+    true
+}
+
 /// 从字典中读取数据 (Generic Read)
-pub fn read_dictionary_value<T: CLTyped + FromBytes>(
-    dictionary_name: &str,
-    key: &str,
-) -> Option<T> {
+fn read_dictionary_value<T: CLTyped + FromBytes>(dictionary_name: &str, key: &str) -> Option<T> {
     // 1. 获取字典的种子 URef
     let dictionary_seed_uref = get_uref(dictionary_name);
 
@@ -54,7 +241,7 @@ pub fn read_dictionary_value<T: CLTyped + FromBytes>(
 }
 
 /// 向字典写入数据 (Generic Write)
-pub fn write_dictionary_value<T: CLTyped + ToBytes>(dictionary_name: &str, key: &str, value: T) {
+fn write_dictionary_value<T: CLTyped + ToBytes>(dictionary_name: &str, key: &str, value: T) {
     // 1. 获取字典的种子 URef
     let dictionary_seed_uref = get_uref(dictionary_name);
 
@@ -66,15 +253,53 @@ pub fn write_dictionary_value<T: CLTyped + ToBytes>(dictionary_name: &str, key:
 // 3. 业务封装函数 (Business Specific)
 // ==========================================
 
-/// 检查这笔交易 Hash 是否已经处理过？
-pub fn is_tx_processed(tx_hash: &str) -> bool {
-    // 从 DICT_PROCESSED_TXS 字典里读，如果没有值，默认为 false
-    read_dictionary_value::<bool>(DICT_PROCESSED_TXS, tx_hash).unwrap_or(false)
+/// 出站 tx_id 是否已被使用
+///
+/// 出站 tx_id 由用户自选，与入站请求 ID 分开存放：
+/// 否则可以用受害者入站请求的 ID 作为出站 tx_id，使该入站请求无法完成
+pub fn is_outbound_tx_used(tx_id: &str) -> bool {
+    read_record::<bool>(StorageKey::OutboundTx(tx_id)).unwrap_or(false)
+}
+
+/// 标记出站 tx_id 已使用，防止重放
+pub fn mark_outbound_tx_used(tx_id: &str) {
+    write_record(StorageKey::OutboundTx(tx_id), true);
+}
+
+/// 入站请求是否已完成
+pub fn is_inbound_processed(request_id: &str) -> bool {
+    read_record::<bool>(StorageKey::InboundRequest(request_id)).unwrap_or(false)
+}
+
+/// 标记入站请求已完成，防止同一源链事件被重复释放
+pub fn mark_inbound_processed(request_id: &str) {
+    write_record(StorageKey::InboundRequest(request_id), true);
+}
+
+/// 读取占用源链事件的入站请求 ID
+///
+/// 请求 ID 包含接收人与金额，同一源链事件换一个接收人或金额就会得到新的 ID；
+/// 按 (源链, 交易哈希, log_index) 记录占用，同一笔存款只能对应一个有效请求
+pub fn read_source_event_claim(
+    src_chain: &str,
+    src_tx_hash: &str,
+    log_index: u64,
+) -> Option<String> {
+    read_record::<Option<String>>(StorageKey::SourceEvent(src_chain, src_tx_hash, log_index))
+        .flatten()
 }
 
-/// 标记交易为“已处理”,防止重放攻击
-pub fn mark_tx_processed(tx_hash: &str) {
-    write_dictionary_value(DICT_PROCESSED_TXS, tx_hash, true);
+/// 写入源链事件的占用者，None 表示释放占用（请求被守护节点拒绝）
+pub fn write_source_event_claim(
+    src_chain: &str,
+    src_tx_hash: &str,
+    log_index: u64,
+    request_id: Option<String>,
+) {
+    write_record(
+        StorageKey::SourceEvent(src_chain, src_tx_hash, log_index),
+        request_id,
+    );
 }
 
 /// 读取管理员地址 (用于权限检查)
@@ -94,29 +319,56 @@ pub fn set_admin(admin: Key) {
 
 /// 读取 Guardian 权重
 pub fn get_guardian_weight(key: &Key) -> Option<u8> {
-    let dictionary_seed_uref = get_uref(DICT_GUARDIANS);
-    storage::dictionary_get(dictionary_seed_uref, &key.to_formatted_string()).unwrap_or_revert()
+    read_record(StorageKey::Guardian(key))
 }
 
-/// 初始化守护节点配置
+/// 初始化守护节点配置，并记录全部守护节点的权重之和
 pub fn save_guardians(guardians: Vec<Guardian>) {
-    let dict = get_uref(DICT_GUARDIANS);
+    let total_weight: u32 = guardians.iter().map(|g| g.weight as u32).sum();
     for g in guardians {
-        storage::dictionary_put(dict, &g.key.to_formatted_string(), g.weight);
+        write_record(StorageKey::Guardian(&g.key), g.weight);
     }
+    let uref = get_or_create_uref(KEY_GUARDIAN_TOTAL_WEIGHT, total_weight);
+    storage::write(uref, total_weight);
+}
+
+/// 读取全部守护节点的权重之和（审批阈值与档位的上限）
+pub fn read_guardian_total_weight() -> u32 {
+    runtime::get_key(KEY_GUARDIAN_TOTAL_WEIGHT)
+        .and_then(|k| k.into_uref())
+        .and_then(|u| storage::read::<u32>(u).unwrap_or_revert())
+        .unwrap_or(0)
 }
 
 /// 创建必要的 NamedKey（字典或单值）
 pub fn ensure_dictionaries() {
     let dicts = [
-        DICT_PROCESSED_TXS,
+        DICT_OUTBOUND_TXS,
+        DICT_INBOUND_REQUESTS,
         DICT_BALANCES,
         DICT_UNLOCK_REQS,
         DICT_GUARDIANS,
         DICT_HOTSWAP,
         DICT_HOTSWAP_VOTES,
         DICT_UNLOCK_VOTES,
-        DICT_CEETH_MINT_REQS,
+        DICT_MINT_REQS,
+        DICT_MINT_VOTES,
+        DICT_FEE_TREASURY,
+        DICT_RELAYERS,
+        DICT_RELAYER_SLASHES,
+        DICT_RELAYER_SLASH_VOTES,
+        DICT_REJECT_VOTES,
+        DICT_REJECTED_SUBMISSIONS,
+        DICT_CHAINS,
+        DICT_ASSETS,
+        DICT_ASSET_ROUTES,
+        DICT_RATE_LIMITS,
+        DICT_PENDING_RELEASES,
+        DICT_APPROVAL_TIERS,
+        DICT_RELEASE_DELAYS,
+        DICT_MINT_AUTHORIZATIONS,
+        DICT_RESERVES,
+        DICT_SOURCE_EVENTS,
     ];
     //遍历字典列表，如果字典不存在，则创建字典，并设置为空
 
@@ -180,13 +432,16 @@ pub fn set_ceeth_token(token: Key) {
     storage::write(uref, token);
 }
 
-/// 读取 ceETH 合约哈希
+/// 读取 ceETH 合约哈希，尚未关联时为 None
+pub fn read_ceeth_token() -> Option<Key> {
+    runtime::get_key(KEY_CEETH_TOKEN)
+        .and_then(|k| k.into_uref())
+        .and_then(|u| storage::read::<Key>(u).unwrap_or_revert())
+}
+
+/// 读取 ceETH 合约哈希（尚未关联时 revert TokenNotSet）
 pub fn get_ceeth_token() -> Key {
-    let uref = get_uref(KEY_CEETH_TOKEN);
-    storage::read(uref)
-        .unwrap_or_revert()
-        .ok_or(BridgeError::MissingKey)
-        .unwrap_or_revert()
+    read_ceeth_token().unwrap_or_revert_with(BridgeError::TokenNotSet)
 }
 
 /// 辅助：获取或创建单值 URef
@@ -213,3 +468,348 @@ pub fn write_active_patch(hash: String) {
     let uref = get_or_create_uref(KEY_ACTIVE_PATCH, hash.clone());
     storage::write(uref, hash);
 }
+
+// ==========================================
+// 4. 偿付能力记账 (Solvency Accounting)
+// ==========================================
+
+/// 读取 U256 计数器，不存在时视为 0
+fn read_u256(name: &str) -> U256 {
+    runtime::get_key(name)
+        .and_then(|k| k.into_uref())
+        .and_then(|u| storage::read::<U256>(u).unwrap_or_revert())
+        .unwrap_or_default()
+}
+
+/// 写入 U256 计数器
+fn write_u256(name: &str, value: U256) {
+    let uref = get_or_create_uref(name, value);
+    storage::write(uref, value);
+}
+
+/// 创建合约自有 purse（仅初始化时调用）
+pub fn create_bridge_purse() {
+    if runtime::get_key(KEY_BRIDGE_PURSE).is_none() {
+        let purse = system::create_purse();
+        runtime::put_key(KEY_BRIDGE_PURSE, purse.into());
+    }
+}
+
+/// 读取合约自有 purse
+pub fn get_bridge_purse() -> URef {
+    get_uref(KEY_BRIDGE_PURSE)
+}
+
+/// 全局本金总额
+pub fn read_total_principal() -> U256 {
+    read_u256(KEY_TOTAL_PRINCIPAL)
+}
+
+pub fn write_total_principal(value: U256) {
+    write_u256(KEY_TOTAL_PRINCIPAL, value);
+}
+
+/// 全局已计提利息
+pub fn read_total_accrued_interest() -> U256 {
+    read_u256(KEY_TOTAL_ACCRUED_INTEREST)
+}
+
+pub fn write_total_accrued_interest(value: U256) {
+    write_u256(KEY_TOTAL_ACCRUED_INTEREST, value);
+}
+
+/// 利息储备余额
+pub fn read_reward_reserve() -> U256 {
+    read_u256(KEY_REWARD_RESERVE)
+}
+
+pub fn write_reward_reserve(value: U256) {
+    write_u256(KEY_REWARD_RESERVE, value);
+}
+
+/// 储备告警水位
+pub fn read_reserve_low_watermark() -> U256 {
+    read_u256(KEY_RESERVE_LOW_WATERMARK)
+}
+
+pub fn write_reserve_low_watermark(value: U256) {
+    write_u256(KEY_RESERVE_LOW_WATERMARK, value);
+}
+
+// ==========================================
+// 5. 协议手续费 (Protocol Fees)
+// ==========================================
+
+/// 读取手续费率 (bps)，未设置时为 0
+pub fn read_fee_bps() -> u32 {
+    runtime::get_key(KEY_FEE_BPS)
+        .and_then(|k| k.into_uref())
+        .and_then(|u| storage::read::<u32>(u).unwrap_or_revert())
+        .unwrap_or(0)
+}
+
+pub fn write_fee_bps(fee_bps: u32) {
+    let uref = get_or_create_uref(KEY_FEE_BPS, fee_bps);
+    storage::write(uref, fee_bps);
+}
+
+/// 读取 Relayer 小费上限 (bps)，未设置时为 0（不允许小费）
+pub fn read_max_relayer_tip_bps() -> u32 {
+    runtime::get_key(KEY_MAX_RELAYER_TIP_BPS)
+        .and_then(|k| k.into_uref())
+        .and_then(|u| storage::read::<u32>(u).unwrap_or_revert())
+        .unwrap_or(0)
+}
+
+pub fn write_max_relayer_tip_bps(tip_bps: u32) {
+    let uref = get_or_create_uref(KEY_MAX_RELAYER_TIP_BPS, tip_bps);
+    storage::write(uref, tip_bps);
+}
+
+/// 读取某资产在金库中累计的手续费
+pub fn read_treasury_balance(asset: &str) -> U256 {
+    read_record(StorageKey::FeeTreasury(asset)).unwrap_or_default()
+}
+
+pub fn write_treasury_balance(asset: &str, balance: U256) {
+    write_record(StorageKey::FeeTreasury(asset), balance);
+}
+
+/// 读取金库管理员（treasurer）
+pub fn get_treasurer() -> Key {
+    let uref = get_uref(KEY_TREASURER);
+    storage::read(uref)
+        .unwrap_or_revert()
+        .ok_or(BridgeError::MissingKey)
+        .unwrap_or_revert()
+}
+
+pub fn set_treasurer(treasurer: Key) {
+    let uref = get_or_create_uref(KEY_TREASURER, treasurer);
+    storage::write(uref, treasurer);
+}
+
+/// 读取利息业绩分成比例 (bps)，未设置时为 0
+pub fn read_performance_fee_bps() -> u32 {
+    runtime::get_key(KEY_PERFORMANCE_FEE_BPS)
+        .and_then(|k| k.into_uref())
+        .and_then(|u| storage::read::<u32>(u).unwrap_or_revert())
+        .unwrap_or(0)
+}
+
+pub fn write_performance_fee_bps(fee_bps: u32) {
+    let uref = get_or_create_uref(KEY_PERFORMANCE_FEE_BPS, fee_bps);
+    storage::write(uref, fee_bps);
+}
+
+/// 协议储备（利息业绩分成）
+pub fn read_protocol_reserve() -> U256 {
+    read_u256(KEY_PROTOCOL_RESERVE)
+}
+
+pub fn write_protocol_reserve(value: U256) {
+    write_u256(KEY_PROTOCOL_RESERVE, value);
+}
+
+// ==========================================
+// 6. Relayer 注册表 (Relayer Registry)
+// ==========================================
+
+/// 读取 Relayer 注册信息
+pub fn read_relayer(relayer: &Key) -> Option<RelayerInfo> {
+    read_record(StorageKey::Relayer(relayer))
+}
+
+pub fn write_relayer(relayer: &Key, info: RelayerInfo) {
+    write_record(StorageKey::Relayer(relayer), info);
+}
+
+/// 最低保证金
+pub fn read_min_relayer_bond() -> U256 {
+    read_u256(KEY_MIN_RELAYER_BOND)
+}
+
+pub fn write_min_relayer_bond(value: U256) {
+    write_u256(KEY_MIN_RELAYER_BOND, value);
+}
+
+/// 保证金退出等待期（毫秒）
+pub fn read_relayer_unbonding_ms() -> u64 {
+    runtime::get_key(KEY_RELAYER_UNBONDING_MS)
+        .and_then(|k| k.into_uref())
+        .and_then(|u| storage::read::<u64>(u).unwrap_or_revert())
+        .unwrap_or(0)
+}
+
+pub fn write_relayer_unbonding_ms(value: u64) {
+    let uref = get_or_create_uref(KEY_RELAYER_UNBONDING_MS, value);
+    storage::write(uref, value);
+}
+
+// ==========================================
+// 7. 链注册表 (Chain Registry)
+// ==========================================
+
+/// 读取链配置
+pub fn read_chain(chain: &str) -> Option<ChainConfig> {
+    read_record(StorageKey::Chain(chain))
+}
+
+pub fn write_chain(config: ChainConfig) {
+    let id = config.id.clone();
+    write_record(StorageKey::Chain(&id), config);
+}
+
+/// 读取已注册链 ID 列表
+pub fn read_chain_ids() -> Vec<String> {
+    runtime::get_key(KEY_CHAIN_IDS)
+        .and_then(|k| k.into_uref())
+        .and_then(|u| storage::read::<Vec<String>>(u).unwrap_or_revert())
+        .unwrap_or_default()
+}
+
+pub fn write_chain_ids(ids: Vec<String>) {
+    let uref = get_or_create_uref(KEY_CHAIN_IDS, ids.clone());
+    storage::write(uref, ids);
+}
+
+// ==========================================
+// 8. 资产注册表 (Asset Registry)
+// ==========================================
+
+/// 读取资产配置
+pub fn read_asset(asset: &str) -> Option<AssetConfig> {
+    read_record(StorageKey::Asset(asset))
+}
+
+pub fn write_asset(config: AssetConfig) {
+    let id = config.id.clone();
+    write_record(StorageKey::Asset(&id), config);
+}
+
+/// 读取资产在指定链上的映射
+pub fn read_asset_route(asset: &str, chain: &str) -> Option<AssetRoute> {
+    read_record(StorageKey::AssetRoute(asset, chain))
+}
+
+pub fn write_asset_route(route: AssetRoute) {
+    let (asset, chain) = (route.asset.clone(), route.chain.clone());
+    write_record(StorageKey::AssetRoute(&asset, &chain), route);
+}
+
+/// 读取已注册资产 ID 列表
+pub fn read_asset_ids() -> Vec<String> {
+    runtime::get_key(KEY_ASSET_IDS)
+        .and_then(|k| k.into_uref())
+        .and_then(|u| storage::read::<Vec<String>>(u).unwrap_or_revert())
+        .unwrap_or_default()
+}
+
+pub fn write_asset_ids(ids: Vec<String>) {
+    let uref = get_or_create_uref(KEY_ASSET_IDS, ids.clone());
+    storage::write(uref, ids);
+}
+
+// ==========================================
+// 9. 入站释放限流 (Rate Limits)
+// ==========================================
+
+/// 读取限流配置与状态（未配置时不限流）
+pub fn read_rate_limit(chain: &str, asset: &str) -> RateLimit {
+    read_record(StorageKey::RateLimit(chain, asset)).unwrap_or_default()
+}
+
+pub fn write_rate_limit(chain: &str, asset: &str, limit: RateLimit) {
+    write_record(StorageKey::RateLimit(chain, asset), limit);
+}
+
+/// 读取排队中的请求（已执行或已取消的条目被清空为 None）
+pub fn read_pending_release(request_id: &str) -> Option<PendingRelease> {
+    read_record::<Option<PendingRelease>>(StorageKey::PendingRelease(request_id)).flatten()
+}
+
+pub fn write_pending_release(pending: PendingRelease) {
+    let id = pending.request_id.clone();
+    write_record(StorageKey::PendingRelease(&id), Some(pending));
+}
+
+/// 请求离开队列（已执行或已取消）时清空条目
+pub fn clear_pending_release(request_id: &str) {
+    write_record(
+        StorageKey::PendingRelease(request_id),
+        Option::<PendingRelease>::None,
+    );
+}
+
+/// 读取资产的大额延迟释放配置（未配置时不延迟）
+pub fn read_release_delay(asset: &str) -> ReleaseDelay {
+    read_record(StorageKey::ReleaseDelay(asset)).unwrap_or_default()
+}
+
+pub fn write_release_delay(asset: &str, delay: ReleaseDelay) {
+    write_record(StorageKey::ReleaseDelay(asset), delay);
+}
+
+// ==========================================
+// 10. 审批档位 (Approval Tiers)
+// ==========================================
+
+/// 读取资产的审批档位（未配置时为空，仅使用基础阈值）
+pub fn read_approval_tiers(asset: &str) -> Vec<ApprovalTier> {
+    read_record(StorageKey::ApprovalTiers(asset)).unwrap_or_default()
+}
+
+pub fn write_approval_tiers(asset: &str, tiers: Vec<ApprovalTier>) {
+    write_record(StorageKey::ApprovalTiers(asset), tiers);
+}
+
+// ==========================================
+// 11. 供应量计数 (Reserves)
+// ==========================================
+
+/// 读取资产的供应量计数
+pub fn read_reserves(asset: &str) -> AssetReserves {
+    read_record(StorageKey::Reserves(asset)).unwrap_or_default()
+}
+
+pub fn write_reserves(asset: &str, reserves: AssetReserves) {
+    write_record(StorageKey::Reserves(asset), reserves);
+}
+
+// ==========================================
+// 12. 事件序号与出站哈希链 (Event Sequencing)
+// ==========================================
+
+/// 分配下一个事件序号（从 0 开始连续递增）
+pub fn next_event_nonce() -> u64 {
+    let uref = get_or_create_uref(KEY_EVENT_NONCE, 0u64);
+    let nonce: u64 = storage::read(uref).unwrap_or_revert().unwrap_or_default();
+    storage::write(uref, nonce + 1);
+    nonce
+}
+
+/// 读取出站哈希链（尚无出站事件时链头为全 0）
+pub fn read_outbound_accumulator() -> OutboundAccumulator {
+    runtime::get_key(KEY_OUTBOUND_ACCUMULATOR)
+        .and_then(|k| k.into_uref())
+        .and_then(|u| storage::read::<OutboundAccumulator>(u).unwrap_or_revert())
+        .unwrap_or_default()
+}
+
+pub fn write_outbound_accumulator(accumulator: OutboundAccumulator) {
+    let uref = get_or_create_uref(KEY_OUTBOUND_ACCUMULATOR, accumulator.clone());
+    storage::write(uref, accumulator);
+}
+
+// ==========================================
+// 13. 铸造权限 (Mint Authorization)
+// ==========================================
+
+/// Bridge 合约包是否已被加入该资产 CEP-18 合约的 minter 列表
+pub fn read_mint_authorized(asset: &str) -> bool {
+    read_record(StorageKey::MintAuthorization(asset)).unwrap_or(false)
+}
+
+pub fn write_mint_authorized(asset: &str, authorized: bool) {
+    write_record(StorageKey::MintAuthorization(asset), authorized);
+}
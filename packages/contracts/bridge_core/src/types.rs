@@ -6,6 +6,7 @@ use casper_types::{ApiError, Key, U256};
 use casper_types_derive::{CLTyped, FromBytes, ToBytes};
 
 #[repr(u16)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum BridgeError {
     PermissionDenied = 65,
     InsufficientFunds = 66,
@@ -25,6 +26,20 @@ pub enum BridgeError {
     Paused = 80,
     TokenNotSet = 81,
     AllowanceTooLow = 82,
+    InvalidFee = 83,
+    RelayerNotRegistered = 84,
+    BondLocked = 85,
+    PayloadMismatch = 86,
+    InvalidRecipient = 87, // 目标链接收地址不可用（如零地址）
+    DustAmount = 88,       // 金额精度无法在目标链上完整表示
+    RateLimitExceeded = 89,
+    ReleaseNotReady = 90, // 延迟释放的等待期未结束
+    ReleaseCancelled = 91,
+    MintNotPermitted = 92,   // Bridge 未被授予该代币的铸造权限
+    InvariantViolated = 93,  // 资产供应量不变量被破坏
+    RelayerTipTooHigh = 94,  // 小费超过管理员配置的上限
+    InvalidTxHash = 95,      // 源链交易哈希不是 32 字节 hex
+    CancelWindowClosed = 96, // 延迟期已结束，排队请求不能再取消
 }
 
 impl From<BridgeError> for ApiError {
@@ -33,6 +48,35 @@ impl From<BridgeError> for ApiError {
     }
 }
 
+/// 资产标识（用于手续费金库等按资产记账的场景）
+pub const ASSET_CSPR: &str = "cspr";
+pub const ASSET_CEETH: &str = "ceeth";
+
+/// 内置资产在本链的精度
+pub const CSPR_DECIMALS: u8 = 9;
+pub const CEETH_DECIMALS: u8 = 18;
+
+/// 本链（Casper）在链注册表中的规范标识
+pub const LOCAL_CHAIN_ID: &str = "casper";
+
+/// 链注册表中的地址格式
+pub const ADDRESS_FORMAT_EVM: u8 = 0; // 20 字节 hex 地址
+
+/// 守护节点投票事件中的投票对象
+pub const VOTE_KIND_UNLOCK: u8 = 0;
+pub const VOTE_KIND_MINT: u8 = 1;
+pub const VOTE_KIND_HOT_SWAP: u8 = 2;
+pub const VOTE_KIND_SLASH: u8 = 3;
+pub const VOTE_KIND_REJECT: u8 = 4;
+
+/// 待释放队列中的请求类型
+pub const RELEASE_KIND_UNLOCK: u8 = 0;
+pub const RELEASE_KIND_MINT: u8 = 1;
+
+/// 资产桥接模式
+pub const ASSET_MODE_LOCK_UNLOCK: u8 = 0; // 本链托管原生资产：出站锁定，入站解锁
+pub const ASSET_MODE_MINT_BURN: u8 = 1; // 本链为包装资产：入站铸造，出站销毁
+
 /// Guardian 节点的权重配置
 #[derive(Clone, Debug, CLTyped, ToBytes, FromBytes)]
 pub struct Guardian {
@@ -59,14 +103,23 @@ pub struct VaultPosition {
 /// 待解锁的跨链请求
 #[derive(Clone, Debug, CLTyped, ToBytes, FromBytes)]
 pub struct UnlockRequest {
-    pub id: String,            // 跨链消息哈希/唯一ID
-    pub recipient: Key,        // 目标链接收人（Casper 侧）
-    pub amount: U256,          // 解锁金额
-    pub src_chain: String,     // 来源链
-    pub dst_chain: String,     // 目标链
-    pub timestamp_ms: u64,     // 请求时间
-    pub finalized: bool,       // 是否已经完成
-    pub approvals_weight: u32, // 已累计的权重
+    pub id: String,             // 跨链消息哈希/唯一ID（由合约根据源链事件计算）
+    pub recipient: Key,         // 目标链接收人（Casper 侧）
+    pub amount: U256,           // 解锁金额（本链精度）
+    pub remote_amount: U256,    // 源链事件中的原始金额（源链精度）
+    pub asset: String,          // 资产标识
+    pub src_chain: String,      // 来源链
+    pub src_tx_hash: String,    // 源链交易哈希
+    pub log_index: u64,         // 源链事件在交易中的日志序号
+    pub dst_chain: String,      // 目标链
+    pub timestamp_ms: u64,      // 请求时间
+    pub finalized: bool,        // 是否已经完成
+    pub approvals_weight: u32,  // 已累计的权重
+    pub required_weight: u32,   // 创建时按金额档位确定的审批权重
+    pub relayer_tip: U256,      // 用户在源链锁仓时设置的 Relayer 小费
+    pub submitter: Key,         // 提交请求的 Relayer，完成时获得小费
+    pub rejections_weight: u32, // 守护节点已累计的拒绝权重
+    pub rejected: bool,         // 已被守护节点拒绝，可由正确内容重新提交
 }
 
 /// 热升级/热修复的 Patch
@@ -83,3 +136,115 @@ pub struct HotSwapPatch {
 pub struct Guardians {
     pub list: Vec<Guardian>,
 }
+
+/// 偿付能力快照（view 入口返回）
+#[derive(Clone, Debug, CLTyped, ToBytes, FromBytes)]
+pub struct SolvencyReport {
+    pub total_principal: U256,        // 全局本金
+    pub total_accrued_interest: U256, // 全局已计提利息
+    pub reward_reserve: U256,         // 剩余利息储备
+    pub protocol_fees: U256,          // 金库中待提取的 CSPR 手续费
+    pub protocol_reserve: U256,       // 利息业绩分成累计
+    pub total_liabilities: U256,      // 本金 + 利息
+    pub locked_backing: U256,         // 出站锁定、尚未解锁的 CSPR（目标链资产的储备）
+    pub purse_balance: U256,          // 合约 purse 实际余额
+    pub solvent: bool,                // purse 余额是否覆盖负债与储备
+}
+
+/// 已注册 Relayer 的保证金信息
+#[derive(Clone, Debug, CLTyped, ToBytes, FromBytes)]
+pub struct RelayerInfo {
+    pub bond: U256,            // 已质押的 CSPR 保证金
+    pub active: bool,          // 是否可以创建请求
+    pub registered_at_ms: u64, // 注册时间
+    pub unbonding_at_ms: u64,  // 保证金可取回时间（0 表示未申请退出）
+}
+
+/// 链注册表条目
+#[derive(Clone, Debug, PartialEq, Eq, CLTyped, ToBytes, FromBytes)]
+pub struct ChainConfig {
+    pub id: String,         // 规范链标识，例如 "ethereum"
+    pub evm_chain_id: u64,  // EVM chain id（非 EVM 链为 0）
+    pub address_format: u8, // 目标链地址格式
+    pub decimals: u8,       // 原生资产精度
+    pub enabled: bool,      // 是否开放该路由
+}
+
+/// 资产注册表条目
+#[derive(Clone, Debug, PartialEq, Eq, CLTyped, ToBytes, FromBytes)]
+pub struct AssetConfig {
+    pub id: String,         // 资产标识，例如 "cspr"、"ceeth"、"usdc"
+    pub token: Option<Key>, // CEP-18 合约哈希；None 表示原生 CSPR
+    pub decimals: u8,       // 本链精度
+    pub mode: u8,           // ASSET_MODE_LOCK_UNLOCK / ASSET_MODE_MINT_BURN
+    pub enabled: bool,
+}
+
+/// 资产在某条远端链上的映射
+///
+/// 金额与手续费上下限按资产的本链精度计，不同精度的资产各自配置
+#[derive(Clone, Debug, PartialEq, Eq, CLTyped, ToBytes, FromBytes)]
+pub struct AssetRoute {
+    pub asset: String,
+    pub chain: String,
+    pub remote_token: Vec<u8>, // 远端代币地址（按链地址格式规范化后的字节）
+    pub remote_decimals: u8,   // 远端代币精度
+    pub enabled: bool,
+    pub min_amount: U256, // 单笔最小金额
+    pub max_amount: U256, // 单笔最大金额（0 表示不设上限）
+    pub min_fee: U256,    // 单笔最低手续费
+    pub max_fee: U256,    // 单笔最高手续费（0 表示不设上限）
+}
+
+/// 入站释放限流配置与状态（按来源链、资产）
+///
+/// 已用额度按 cap / window_ms 的速率线性恢复，近似滚动窗口
+#[derive(Clone, Debug, Default, CLTyped, ToBytes, FromBytes)]
+pub struct RateLimit {
+    pub cap: U256,          // 窗口内允许释放的最大金额（0 表示不限流）
+    pub window_ms: u64,     // 滚动窗口长度
+    pub used: U256,         // 截至 updated_at_ms 的已用额度
+    pub updated_at_ms: u64, // 上次占用额度的时间
+}
+
+/// 因超出限流或金额较大而排队等待释放的请求
+#[derive(Clone, Debug, CLTyped, ToBytes, FromBytes)]
+pub struct PendingRelease {
+    pub request_id: String,
+    pub kind: u8,      // RELEASE_KIND_UNLOCK / RELEASE_KIND_MINT
+    pub asset: String, // 请求的资产，铸造请求按资产存放
+    pub queued_at_ms: u64,
+    pub release_after_ms: u64, // 最早可执行时间，之前守护节点可投票取消
+}
+
+/// 大额释放的延迟配置（按资产）
+#[derive(Clone, Debug, Default, CLTyped, ToBytes, FromBytes)]
+pub struct ReleaseDelay {
+    pub amount_threshold: U256, // 金额超过该值的请求进入延迟队列
+    pub delay_ms: u64,          // 延迟时长（0 表示不延迟）
+}
+
+/// 审批档位：金额不超过 max_amount 的请求需要 required_weight 的守护权重
+#[derive(Clone, Debug, PartialEq, Eq, CLTyped, ToBytes, FromBytes)]
+pub struct ApprovalTier {
+    pub max_amount: U256,
+    pub required_weight: u32,
+}
+
+/// 资产供应量计数（按资产），用于储备证明与不变量检查
+#[derive(Clone, Debug, Default, PartialEq, Eq, CLTyped, ToBytes, FromBytes)]
+pub struct AssetReserves {
+    pub total_locked: U256,           // 锁入 Bridge 托管的总量
+    pub total_released: U256,         // 从托管释放的总量
+    pub total_minted: U256,           // 铸造总量（含手续费与小费部分）
+    pub total_burned: U256,           // 销毁总量
+    pub attested_remote_locked: U256, // 守护节点确认的远端锁仓总量
+}
+
+/// 出站事件哈希链：root_n = blake2b(root_{n-1} ‖ nonce ‖ 事件字节)
+#[derive(Clone, Debug, Default, PartialEq, Eq, CLTyped, ToBytes, FromBytes)]
+pub struct OutboundAccumulator {
+    pub root: [u8; 32],  // 当前链头（初始为全 0）
+    pub count: u64,      // 已累积的出站事件数
+    pub last_nonce: u64, // 最近一个出站事件的 nonce
+}
@@ -1,7 +1,10 @@
 //! 通用工具函数
 extern crate alloc;
 
-use casper_types::U256;
+use alloc::{string::String, vec::Vec};
+use casper_types::{bytesrepr::ToBytes, crypto::blake2b, Key, U256};
+
+use crate::types::{ApprovalTier, AssetReserves, BridgeError, UnlockRequest};
 
 /// 基于毫秒时间增量与年化 APR (bps) 计算利息
 pub fn compute_yield(principal: U256, apr_bps: u32, delta_ms: u64) -> U256 {
@@ -11,10 +14,200 @@ pub fn compute_yield(principal: U256, apr_bps: u32, delta_ms: u64) -> U256 {
 
     // APR 基于基点 (bps)，一年按 365 天
     // interest = principal * apr_bps / 10_000 * delta_ms / MS_PER_YEAR
-    const MS_PER_YEAR: u128 = 365 * 24 * 60 * 60 * 1000;
+    const MS_PER_YEAR: u64 = 365 * 24 * 60 * 60 * 1000;
+
+    // 全程使用 U256，本金超过 u128 时不会 panic
+    let num = principal
+        .saturating_mul(U256::from(apr_bps))
+        .saturating_mul(U256::from(delta_ms));
+
+    num / (U256::from(10_000u64) * U256::from(MS_PER_YEAR))
+}
+
+/// 按费率 (bps) 计算手续费，并夹在 [min_fee, max_fee] 区间内（max_fee 为 0 表示不设上限）
+/// 手续费不会超过转账金额本身
+pub fn compute_fee(amount: U256, fee_bps: u32, min_fee: U256, max_fee: U256) -> U256 {
+    if amount.is_zero() {
+        return U256::zero();
+    }
+
+    let mut fee = amount.saturating_mul(U256::from(fee_bps)) / U256::from(10_000u32);
+    if fee < min_fee {
+        fee = min_fee;
+    }
+    if !max_fee.is_zero() && fee > max_fee {
+        fee = max_fee;
+    }
+    if fee > amount {
+        fee = amount;
+    }
+    fee
+}
+
+/// 按金额档位计算请求所需的审批权重
+///
+/// tiers 按 max_amount 升序排列，取第一个能容纳 amount 的档位，超过所有档位时取最高档；
+/// 结果不低于基础阈值
+pub fn required_approval_weight(amount: U256, tiers: &[ApprovalTier], base_threshold: u32) -> u32 {
+    tiers
+        .iter()
+        .find(|tier| amount <= tier.max_amount)
+        .or(tiers.last())
+        .map(|tier| tier.required_weight)
+        .unwrap_or(0)
+        .max(base_threshold)
+}
+
+/// 滚动窗口限流：已用额度按 cap / window_ms 的速率线性恢复，返回经过 elapsed_ms 后的已用额度
+pub fn decay_rate_limit_usage(used: U256, cap: U256, window_ms: u64, elapsed_ms: u64) -> U256 {
+    if window_ms == 0 || elapsed_ms >= window_ms {
+        return U256::zero();
+    }
+    let recovered = cap.saturating_mul(U256::from(elapsed_ms)) / U256::from(window_ms);
+    used.saturating_sub(recovered)
+}
+
+/// 限流是否允许在已用额度为 used 时再释放 amount（cap 为 0 表示不限流）
+pub fn rate_limit_admits(used: U256, cap: U256, amount: U256) -> bool {
+    cap.is_zero() || used.saturating_add(amount) <= cap
+}
 
-    let num = principal.as_u128() * apr_bps as u128 * delta_ms as u128;
+/// 校验资产供应量不变量
+///
+/// 释放不超过锁入、销毁不超过铸造、铸造不超过守护节点确认的远端锁仓；
+/// 传入 CEP-18 实际流通量时，流通量还不能超过尚未赎回的远端锁仓（已确认 - 已销毁）
+pub fn check_supply_invariants(
+    reserves: &AssetReserves,
+    token_supply: Option<U256>,
+) -> Result<(), BridgeError> {
+    let backed = reserves
+        .attested_remote_locked
+        .saturating_sub(reserves.total_burned);
+    if reserves.total_released > reserves.total_locked
+        || reserves.total_burned > reserves.total_minted
+        || reserves.total_minted > reserves.attested_remote_locked
+        || token_supply.is_some_and(|supply| supply > backed)
+    {
+        return Err(BridgeError::InvariantViolated);
+    }
+    Ok(())
+}
+
+/// 出站哈希链的下一个链头
+///
+/// blake2b-256(prev_root ‖ nonce ‖ payload)，nonce 为 u64 小端，payload 为事件字节并带 u32 长度前缀
+pub fn chain_outbound_root(prev_root: &[u8; 32], nonce: u64, payload: &[u8]) -> [u8; 32] {
+    let mut preimage = Vec::new();
+    preimage.extend_from_slice(prev_root);
+    preimage.extend_from_slice(&nonce.to_le_bytes());
+    push_field(&mut preimage, payload);
+    blake2b(preimage)
+}
+
+/// 在两种精度之间换算金额
+///
+/// 向低精度换算时存在无法表示的尾数（dust）返回 `DustAmount`，向高精度换算溢出返回 `InvalidAmount`
+pub fn scale_amount(amount: U256, from_decimals: u8, to_decimals: u8) -> Result<U256, BridgeError> {
+    let factor = decimals_factor(from_decimals, to_decimals)?;
+    if from_decimals > to_decimals && !(amount % factor).is_zero() {
+        return Err(BridgeError::DustAmount);
+    }
+    scale_amount_floor(amount, from_decimals, to_decimals)
+}
+
+/// 在两种精度之间换算金额，向低精度换算时舍去无法表示的尾数
+pub fn scale_amount_floor(
+    amount: U256,
+    from_decimals: u8,
+    to_decimals: u8,
+) -> Result<U256, BridgeError> {
+    let factor = decimals_factor(from_decimals, to_decimals)?;
+    if from_decimals > to_decimals {
+        Ok(amount / factor)
+    } else {
+        amount.checked_mul(factor).ok_or(BridgeError::InvalidAmount)
+    }
+}
+
+/// 两种精度之间的换算系数 10^|from - to|
+fn decimals_factor(from_decimals: u8, to_decimals: u8) -> Result<U256, BridgeError> {
+    U256::from(10u8)
+        .checked_pow(U256::from(from_decimals.abs_diff(to_decimals)))
+        .ok_or(BridgeError::InvalidAmount)
+}
+
+/// 追加一个带 u32 长度前缀的字段，避免字段拼接产生歧义
+pub(crate) fn push_field(preimage: &mut Vec<u8>, field: &[u8]) {
+    preimage.extend_from_slice(&(field.len() as u32).to_le_bytes());
+    preimage.extend_from_slice(field);
+}
+
+/// 规范化源链交易哈希：可带 0x/0X 前缀，大小写不限，必须恰好为 32 字节
+pub fn normalize_tx_hash(src_tx_hash: &str) -> Result<[u8; 32], BridgeError> {
+    let digits = src_tx_hash
+        .strip_prefix("0x")
+        .or_else(|| src_tx_hash.strip_prefix("0X"))
+        .unwrap_or(src_tx_hash);
+    let mut bytes = [0u8; 32];
+    hex::decode_to_slice(digits, &mut bytes).map_err(|_| BridgeError::InvalidTxHash)?;
+    Ok(bytes)
+}
+
+/// 交易哈希的规范文本形式："0x" + 64 位小写 hex
+pub fn canonical_tx_hash(src_tx_hash: &str) -> Result<String, BridgeError> {
+    let bytes = normalize_tx_hash(src_tx_hash)?;
+    let mut canonical = String::from("0x");
+    canonical.push_str(&hex::encode(bytes));
+    Ok(canonical)
+}
+
+/// 根据源链事件计算规范化的跨链消息 ID
+///
+/// blake2b-256(src_chain ‖ src_tx_hash ‖ log_index ‖ recipient ‖ amount ‖ asset) 的小写 hex，
+/// 每个字段以 u32 小端长度为前缀；recipient 与 amount 使用 casper `ToBytes` 编码，
+/// log_index 为 u64 小端。src_tx_hash 先按 `normalize_tx_hash` 解码为 32 字节再参与哈希，
+/// 前缀与大小写不同的写法得到相同 ID；无法解码时返回 InvalidTxHash。
+pub fn compute_message_id(
+    src_chain: &str,
+    src_tx_hash: &str,
+    log_index: u64,
+    recipient: &Key,
+    amount: U256,
+    asset: &str,
+) -> Result<String, BridgeError> {
+    let tx_hash = normalize_tx_hash(src_tx_hash)?;
+    let mut preimage = Vec::new();
+    push_field(&mut preimage, src_chain.as_bytes());
+    push_field(&mut preimage, &tx_hash);
+    push_field(&mut preimage, &log_index.to_le_bytes());
+    push_field(&mut preimage, &recipient.to_bytes().unwrap_or_default());
+    push_field(&mut preimage, &amount.to_bytes().unwrap_or_default());
+    push_field(&mut preimage, asset.as_bytes());
+    Ok(hex::encode(blake2b(preimage)))
+}
 
-    let interest = num / (10_000u128 * MS_PER_YEAR);
-    U256::from(interest)
+/// 计算请求内容摘要，守护节点审批时需提交与源链核对过的摘要
+///
+/// 覆盖除投票状态外的全部请求内容（id、接收人、本链/源链金额、资产、源链信息、目标链、小费）。
+/// src_tx_hash 与消息 ID 一样按 `normalize_tx_hash` 解码后的 32 字节参与哈希。
+pub fn compute_payload_digest(req: &UnlockRequest) -> Result<String, BridgeError> {
+    let tx_hash = normalize_tx_hash(&req.src_tx_hash)?;
+    let mut preimage = Vec::new();
+    push_field(&mut preimage, req.id.as_bytes());
+    push_field(&mut preimage, &req.recipient.to_bytes().unwrap_or_default());
+    push_field(&mut preimage, &req.amount.to_bytes().unwrap_or_default());
+    push_field(
+        &mut preimage,
+        &req.remote_amount.to_bytes().unwrap_or_default(),
+    );
+    push_field(&mut preimage, req.asset.as_bytes());
+    push_field(&mut preimage, req.src_chain.as_bytes());
+    push_field(&mut preimage, &tx_hash);
+    push_field(&mut preimage, &req.log_index.to_le_bytes());
+    push_field(&mut preimage, req.dst_chain.as_bytes());
+    push_field(
+        &mut preimage,
+        &req.relayer_tip.to_bytes().unwrap_or_default(),
+    );
+    Ok(hex::encode(blake2b(preimage)))
 }
@@ -0,0 +1,70 @@
+//! Integration tests for destination address parsing.
+
+use bridge_core::{
+    parse_address, parse_evm_address, parse_token_address, to_checksum_address, BridgeError,
+    ADDRESS_FORMAT_EVM,
+};
+
+// EIP-55 规范中的示例地址
+const CHECKSUMMED: [&str; 4] = [
+    "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+    "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+    "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+    "0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+];
+
+#[test]
+fn checksummed_addresses_round_trip() {
+    for address in CHECKSUMMED {
+        let bytes = parse_evm_address(address).unwrap();
+        assert_eq!(to_checksum_address(&bytes), address);
+        // 全小写视为未带校验和，同样接受
+        assert_eq!(parse_evm_address(&address.to_lowercase()).unwrap(), bytes);
+    }
+}
+
+#[test]
+fn rejects_bad_checksum_and_malformed_input() {
+    // 最后一位大小写错误
+    assert_eq!(
+        parse_evm_address("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD"),
+        Err(BridgeError::InvalidAddress)
+    );
+    // 缺少 0x 前缀、长度不足、非 hex 字符
+    assert!(parse_evm_address("5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed").is_err());
+    assert!(parse_evm_address("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeA").is_err());
+    assert!(parse_evm_address("0xzzAeb6053F3E94C9b9A09f33669435E7Ef1BeAed").is_err());
+}
+
+#[test]
+fn unknown_format_is_rejected() {
+    assert_eq!(
+        parse_address(ADDRESS_FORMAT_EVM, CHECKSUMMED[0])
+            .unwrap()
+            .len(),
+        20
+    );
+    assert_eq!(
+        parse_address(42, CHECKSUMMED[0]),
+        Err(BridgeError::InvalidAddress)
+    );
+}
+
+#[test]
+fn zero_address_is_not_a_valid_recipient() {
+    let zero = "0x0000000000000000000000000000000000000000";
+    assert_eq!(parse_evm_address(zero), Err(BridgeError::InvalidRecipient));
+    assert_eq!(
+        parse_address(ADDRESS_FORMAT_EVM, zero),
+        Err(BridgeError::InvalidRecipient)
+    );
+    // 远端代币的零地址表示原生资产，仍然允许
+    assert_eq!(
+        parse_token_address(ADDRESS_FORMAT_EVM, zero),
+        Ok(vec![0u8; 20])
+    );
+    assert_eq!(
+        parse_token_address(ADDRESS_FORMAT_EVM, CHECKSUMMED[1]).unwrap(),
+        parse_evm_address(CHECKSUMMED[1]).unwrap().to_vec()
+    );
+}
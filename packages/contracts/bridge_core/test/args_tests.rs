@@ -0,0 +1,62 @@
+//! Client-side RuntimeArgs builders.
+#![cfg(feature = "client")]
+
+use bridge_core::args;
+use casper_types::{account::AccountHash, Key, U256};
+
+#[test]
+fn builders_use_entry_point_parameter_names() {
+    let recipient = Key::Account(AccountHash::new([1u8; 32]));
+    let runtime_args = args::withdraw_fees("cspr".into(), U256::from(5u64), recipient);
+
+    assert_eq!(runtime_args.len(), 3);
+    assert_eq!(
+        runtime_args
+            .get("amount")
+            .unwrap()
+            .clone()
+            .into_t::<U256>()
+            .unwrap(),
+        U256::from(5u64)
+    );
+    assert_eq!(
+        runtime_args
+            .get("recipient")
+            .unwrap()
+            .clone()
+            .into_t::<Key>()
+            .unwrap(),
+        recipient
+    );
+    assert!(args::get_supported_chains().is_empty());
+}
+
+#[test]
+fn install_omits_unset_ceeth_token() {
+    let admin = Key::Account(AccountHash::new([2u8; 32]));
+
+    let minter = Key::Account(AccountHash::new([3u8; 32]));
+
+    let without = args::install(admin, Vec::new(), 1, 500, None, vec![minter]);
+    assert!(without.get("ceeth_token").is_none());
+    assert!(without.get("ceeth_revoked_minters").is_none());
+
+    let token = Key::Hash([9u8; 32]);
+    let with = args::install(admin, Vec::new(), 1, 500, Some(token), vec![minter]);
+    assert_eq!(
+        with.get("ceeth_token")
+            .unwrap()
+            .clone()
+            .into_t::<Key>()
+            .unwrap(),
+        token
+    );
+    assert_eq!(
+        with.get("ceeth_revoked_minters")
+            .unwrap()
+            .clone()
+            .into_t::<Vec<Key>>()
+            .unwrap(),
+        vec![minter]
+    );
+}
@@ -0,0 +1,990 @@
+//! Integration tests for ceETH minting through a real CEP-18 token.
+//!
+//! 需要先编译 `bridge_core.wasm` 与 `cep18.wasm`（wasm32-unknown-unknown, release），
+//! 并放在测试运行目录的 wasm 搜索路径中。
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, LmdbWasmTestBuilder, TransferRequestBuilder, DEFAULT_ACCOUNT_ADDR,
+    LOCAL_GENESIS_REQUEST,
+};
+use casper_types::{
+    account::AccountHash, bytesrepr::ToBytes, runtime_args, AddressableEntityHash, CLValue,
+    EntityAddr, Key, RuntimeArgs, StoredValue, U256,
+};
+
+use bridge_core::{
+    compute_message_id, compute_payload_digest, ApprovalTier, BridgeError, Guardian, UnlockRequest,
+    ASSET_CEETH, LOCAL_CHAIN_ID,
+};
+
+const BRIDGE_WASM: &str = "bridge_core.wasm";
+const CEP18_WASM: &str = "cep18.wasm";
+const BRIDGE_CONTRACT_KEY: &str = "bridge_core_contract_hash";
+const BRIDGE_PACKAGE_KEY: &str = "bridge_core_package_hash";
+const TOKEN_NAME: &str = "ceETH";
+const SRC_CHAIN: &str = "ethereum";
+const SRC_TX_HASH: &str = "0x5f1c0d7cbb1e4a2f9e8a7b6c5d4e3f2a1b0c9d8e7f6a5b4c3d2e1f0a9b8c7d6e";
+
+struct TestContext {
+    builder: LmdbWasmTestBuilder,
+    bridge_package: Key,
+    token: Key,
+}
+
+fn token_contract_key() -> String {
+    format!("cep18_contract_hash_{TOKEN_NAME}")
+}
+
+fn default_account() -> Key {
+    Key::Account(*DEFAULT_ACCOUNT_ADDR)
+}
+
+fn named_key(builder: &LmdbWasmTestBuilder, addr: EntityAddr, name: &str) -> Key {
+    *builder
+        .get_named_keys(addr)
+        .get(name)
+        .unwrap_or_else(|| panic!("missing named key {name}"))
+}
+
+fn call_bridge(builder: &mut LmdbWasmTestBuilder, entry_point: &str, args: RuntimeArgs) {
+    call_contract(builder, BRIDGE_CONTRACT_KEY, entry_point, args);
+}
+
+fn call_contract(
+    builder: &mut LmdbWasmTestBuilder,
+    contract_key: &str,
+    entry_point: &str,
+    args: RuntimeArgs,
+) {
+    let request = ExecuteRequestBuilder::contract_call_by_name(
+        *DEFAULT_ACCOUNT_ADDR,
+        contract_key,
+        entry_point,
+        args,
+    )
+    .build();
+    builder.exec(request);
+}
+
+/// 以其他账户身份调用 Bridge（按合约哈希，调用者没有 Bridge 的 named key）
+fn call_bridge_as(
+    builder: &mut LmdbWasmTestBuilder,
+    sender: AccountHash,
+    entry_point: &str,
+    args: RuntimeArgs,
+) {
+    let account_addr = EntityAddr::Account(DEFAULT_ACCOUNT_ADDR.value());
+    let bridge = named_key(builder, account_addr, BRIDGE_CONTRACT_KEY)
+        .into_hash_addr()
+        .expect("bridge contract hash");
+    let request = ExecuteRequestBuilder::contract_call_by_hash(
+        sender,
+        AddressableEntityHash::new(bridge),
+        entry_point,
+        args,
+    )
+    .build();
+    builder.exec(request);
+}
+
+/// 创建并充值账户，使其可以发起部署
+fn fund_account(builder: &mut LmdbWasmTestBuilder, account: AccountHash) {
+    let transfer = TransferRequestBuilder::new(100_000_000_000_000u64, account).build();
+    builder.transfer_and_commit(transfer).expect_success();
+}
+
+/// 安装 Bridge 与 ceETH（CEP-18），默认账户为唯一守护节点
+fn setup(authorize_mint: bool) -> TestContext {
+    let guardians = vec![Guardian {
+        key: default_account(),
+        weight: 1,
+    }];
+    setup_with_guardians(authorize_mint, guardians, 1)
+}
+
+/// 安装 Bridge 与 ceETH（CEP-18），Bridge 合约包为唯一 minter
+fn setup_with_guardians(
+    authorize_mint: bool,
+    guardians: Vec<Guardian>,
+    threshold: u32,
+) -> TestContext {
+    let mut builder = LmdbWasmTestBuilder::default();
+    builder.run_genesis(LOCAL_GENESIS_REQUEST.clone()).commit();
+
+    let install_bridge = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        BRIDGE_WASM,
+        runtime_args! {
+            "admin" => default_account(),
+            "guardians" => guardians,
+            "threshold" => threshold,
+            "base_apr_bps" => 0u32,
+        },
+    )
+    .build();
+    builder.exec(install_bridge).expect_success().commit();
+
+    let account_addr = EntityAddr::Account(DEFAULT_ACCOUNT_ADDR.value());
+    let bridge_package = named_key(&builder, account_addr, BRIDGE_PACKAGE_KEY);
+    let token = install_token(&mut builder, vec![bridge_package]);
+
+    call_bridge(
+        &mut builder,
+        "set_ceeth_token",
+        runtime_args! { "token" => token },
+    );
+    builder.expect_success().commit();
+    configure_bridge(&mut builder);
+
+    if authorize_mint {
+        call_bridge(
+            &mut builder,
+            "set_mint_authorized",
+            runtime_args! { "asset" => ASSET_CEETH, "authorized" => true },
+        );
+        builder.expect_success().commit();
+    }
+
+    TestContext {
+        builder,
+        bridge_package,
+        token,
+    }
+}
+
+/// 由默认账户安装 ceETH（CEP-18），返回代币合约 Key
+fn install_token(builder: &mut LmdbWasmTestBuilder, minters: Vec<Key>) -> Key {
+    let install_token = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CEP18_WASM,
+        runtime_args! {
+            "name" => TOKEN_NAME,
+            "symbol" => "CEETH",
+            "decimals" => 18u8,
+            "total_supply" => U256::zero(),
+            "enable_mint_burn" => 1u8,
+            "admin_list" => vec![default_account()],
+            "minter_list" => minters,
+        },
+    )
+    .build();
+    builder.exec(install_token).expect_success().commit();
+    let account_addr = EntityAddr::Account(DEFAULT_ACCOUNT_ADDR.value());
+    named_key(builder, account_addr, &token_contract_key())
+}
+
+/// 配置来源链、ceETH 路由，并将默认账户注册为 Relayer
+fn configure_bridge(builder: &mut LmdbWasmTestBuilder) {
+    let setup_calls = vec![
+        (
+            "set_chain",
+            runtime_args! {
+                "id" => SRC_CHAIN,
+                "evm_chain_id" => 1u64,
+                "address_format" => 0u8,
+                "decimals" => 18u8,
+                "enabled" => true,
+            },
+        ),
+        (
+            "set_asset_route",
+            runtime_args! {
+                "asset" => ASSET_CEETH,
+                "chain" => SRC_CHAIN,
+                "remote_token" => "0x0000000000000000000000000000000000000000",
+                "remote_decimals" => 18u8,
+                "enabled" => true,
+                "min_amount" => U256::one(),
+                "max_amount" => U256::zero(),
+            },
+        ),
+        (
+            "set_relayer_bond_config",
+            runtime_args! { "min_bond" => U256::zero(), "unbonding_ms" => 0u64 },
+        ),
+        (
+            "register_relayer",
+            runtime_args! { "bond" => U256::from(1_000_000_000u64) },
+        ),
+    ];
+    for (entry_point, args) in setup_calls {
+        call_bridge(builder, entry_point, args);
+        builder.expect_success().commit();
+    }
+}
+
+fn create_mint_request_args(log_index: u64, recipient: Key, amount: U256) -> RuntimeArgs {
+    create_mint_request_args_with_tip(log_index, recipient, amount, None)
+}
+
+fn create_mint_request_args_with_tip(
+    log_index: u64,
+    recipient: Key,
+    amount: U256,
+    relayer_tip: Option<U256>,
+) -> RuntimeArgs {
+    runtime_args! {
+        "src_chain" => SRC_CHAIN,
+        "src_tx_hash" => SRC_TX_HASH,
+        "log_index" => log_index,
+        "recipient" => recipient,
+        "amount" => amount,
+        "asset" => ASSET_CEETH,
+        "dst_chain" => LOCAL_CHAIN_ID,
+        "relayer_tip" => relayer_tip,
+    }
+}
+
+/// 与合约内一致地构造请求（摘要不包含时间戳、投票状态与 submitter）
+fn request(log_index: u64, recipient: Key, amount: U256) -> UnlockRequest {
+    let request_id = compute_message_id(
+        SRC_CHAIN,
+        SRC_TX_HASH,
+        log_index,
+        &recipient,
+        amount,
+        ASSET_CEETH,
+    )
+    .unwrap();
+    UnlockRequest {
+        id: request_id,
+        recipient,
+        amount,
+        remote_amount: amount,
+        asset: ASSET_CEETH.into(),
+        src_chain: SRC_CHAIN.into(),
+        src_tx_hash: SRC_TX_HASH.into(),
+        log_index,
+        dst_chain: LOCAL_CHAIN_ID.into(),
+        timestamp_ms: 0,
+        finalized: false,
+        approvals_weight: 0,
+        required_weight: 0,
+        relayer_tip: U256::zero(),
+        submitter: default_account(),
+        rejections_weight: 0,
+        rejected: false,
+    }
+}
+
+/// 计算请求 ID 与摘要
+fn expected_digest(log_index: u64, recipient: Key, amount: U256) -> (String, String) {
+    let req = request(log_index, recipient, amount);
+    let digest = compute_payload_digest(&req).unwrap();
+    (req.id, digest)
+}
+
+/// 断言上一次执行以指定的 BridgeError 失败
+fn assert_bridge_error(builder: &mut LmdbWasmTestBuilder, expected: BridgeError) {
+    builder.expect_failure();
+    let error = format!("{:?}", builder.get_error().expect("should have error"));
+    assert!(
+        error.contains(&format!("User({})", expected as u16)),
+        "unexpected error: {error}"
+    );
+}
+
+/// 读取 CEP-18 balances 字典中 owner 的余额
+fn token_balance(builder: &LmdbWasmTestBuilder, token: Key, owner: Key) -> U256 {
+    let token_addr = EntityAddr::SmartContract(token.into_hash_addr().expect("token hash"));
+    let balances = named_key(builder, token_addr, "balances")
+        .into_uref()
+        .expect("balances uref");
+    let item_key = STANDARD.encode(owner.to_bytes().unwrap());
+    match builder.query_dictionary_item(None, balances, &item_key) {
+        Ok(StoredValue::CLValue(value)) => CLValue::into_t(value).unwrap(),
+        _ => U256::zero(),
+    }
+}
+
+#[test]
+fn approved_request_mints_ceeth_to_recipient() {
+    let TestContext {
+        mut builder,
+        bridge_package,
+        token,
+    } = setup(true);
+    let recipient = Key::Account(AccountHash::new([42u8; 32]));
+    let amount = U256::from(5_000_000_000_000_000u64);
+
+    call_bridge(
+        &mut builder,
+        "create_mint_request",
+        create_mint_request_args(0, recipient, amount),
+    );
+    builder.expect_success().commit();
+    assert_eq!(token_balance(&builder, token, recipient), U256::zero());
+
+    let (request_id, digest) = expected_digest(0, recipient, amount);
+    call_bridge(
+        &mut builder,
+        "approve_ceeth_mint",
+        runtime_args! { "request_id" => request_id, "payload_digest" => digest },
+    );
+    builder.expect_success().commit();
+
+    // 未设置手续费与小费时全额铸造给接收人
+    assert_eq!(token_balance(&builder, token, recipient), amount);
+    assert_eq!(token_balance(&builder, token, bridge_package), U256::zero());
+}
+
+#[test]
+fn minting_without_authorization_is_rejected() {
+    let TestContext { mut builder, .. } = setup(false);
+    let recipient = Key::Account(AccountHash::new([42u8; 32]));
+
+    call_bridge(
+        &mut builder,
+        "create_mint_request",
+        create_mint_request_args(0, recipient, U256::from(1_000u64)),
+    );
+    assert_bridge_error(&mut builder, BridgeError::MintNotPermitted);
+}
+
+#[test]
+fn relayer_can_only_be_slashed_for_a_rejected_submission() {
+    let TestContext { mut builder, .. } = setup(true);
+    let recipient = Key::Account(AccountHash::new([42u8; 32]));
+    let amount = U256::from(1_000u64);
+
+    call_bridge(
+        &mut builder,
+        "create_mint_request",
+        create_mint_request_args(0, recipient, amount),
+    );
+    builder.expect_success().commit();
+    let (request_id, digest) = expected_digest(0, recipient, amount);
+    let slash_args = runtime_args! {
+        "relayer" => default_account(),
+        "request_id" => request_id.clone(),
+    };
+
+    // 请求未被拒绝，不能罚没
+    call_bridge(&mut builder, "slash_relayer", slash_args.clone());
+    assert_bridge_error(&mut builder, BridgeError::InvalidRequest);
+
+    call_bridge(
+        &mut builder,
+        "reject_mint",
+        runtime_args! {
+            "asset" => ASSET_CEETH,
+            "request_id" => request_id.clone(),
+            "payload_digest" => digest.clone(),
+        },
+    );
+    builder.expect_success().commit();
+
+    // 被拒绝的请求不能再审批
+    call_bridge(
+        &mut builder,
+        "approve_ceeth_mint",
+        runtime_args! { "request_id" => request_id, "payload_digest" => digest },
+    );
+    assert_bridge_error(&mut builder, BridgeError::InvalidRequest);
+
+    call_bridge(&mut builder, "slash_relayer", slash_args);
+    builder.expect_success().commit();
+}
+
+#[test]
+fn rejected_request_can_be_superseded_with_correct_content() {
+    let TestContext {
+        mut builder, token, ..
+    } = setup(true);
+    let recipient = Key::Account(AccountHash::new([42u8; 32]));
+    let amount = U256::from(1_000u64);
+    call_bridge(
+        &mut builder,
+        "set_max_relayer_tip_bps",
+        runtime_args! { "tip_bps" => 5_000u32 },
+    );
+    builder.expect_success().commit();
+
+    // 抢先以错误的小费提交同一源链事件
+    call_bridge(
+        &mut builder,
+        "create_mint_request",
+        create_mint_request_args_with_tip(0, recipient, amount, Some(U256::from(400u64))),
+    );
+    builder.expect_success().commit();
+
+    // 未被拒绝前，不同内容的提交直接失败
+    call_bridge(
+        &mut builder,
+        "create_mint_request",
+        create_mint_request_args(0, recipient, amount),
+    );
+    assert_bridge_error(&mut builder, BridgeError::PayloadMismatch);
+
+    let (request_id, _) = expected_digest(0, recipient, amount);
+    let wrong_digest = {
+        let mut req = request(0, recipient, amount);
+        req.relayer_tip = U256::from(400u64);
+        compute_payload_digest(&req).unwrap()
+    };
+    call_bridge(
+        &mut builder,
+        "reject_mint",
+        runtime_args! {
+            "asset" => ASSET_CEETH,
+            "request_id" => request_id.clone(),
+            "payload_digest" => wrong_digest,
+        },
+    );
+    builder.expect_success().commit();
+
+    call_bridge(
+        &mut builder,
+        "create_mint_request",
+        create_mint_request_args(0, recipient, amount),
+    );
+    builder.expect_success().commit();
+
+    let (_, digest) = expected_digest(0, recipient, amount);
+    call_bridge(
+        &mut builder,
+        "approve_ceeth_mint",
+        runtime_args! { "request_id" => request_id, "payload_digest" => digest },
+    );
+    builder.expect_success().commit();
+    assert_eq!(token_balance(&builder, token, recipient), amount);
+}
+
+#[test]
+fn source_event_cannot_be_replayed_with_different_content() {
+    let TestContext {
+        mut builder, token, ..
+    } = setup(true);
+    let recipient = Key::Account(AccountHash::new([42u8; 32]));
+    let amount = U256::from(1_000u64);
+    create_and_approve_mint(&mut builder, 0, recipient, amount);
+
+    // 换接收人或金额会得到新的请求 ID，但源链事件已被占用
+    let attacker = Key::Account(AccountHash::new([66u8; 32]));
+    for args in [
+        create_mint_request_args(0, attacker, amount),
+        create_mint_request_args(0, recipient, amount * 2),
+    ] {
+        call_bridge(&mut builder, "create_mint_request", args);
+        assert_bridge_error(&mut builder, BridgeError::TxAlreadyProcessed);
+    }
+    assert_eq!(token_balance(&builder, token, attacker), U256::zero());
+    assert_eq!(token_balance(&builder, token, recipient), amount);
+}
+
+#[test]
+fn outbound_tx_id_cannot_block_an_inbound_request() {
+    let TestContext {
+        mut builder,
+        bridge_package,
+        token,
+    } = setup(true);
+    let amount = U256::from(1_000u64);
+
+    // 先为调用者铸造 ceETH，并授权 Bridge 收回以便销毁
+    call_bridge(
+        &mut builder,
+        "create_mint_request",
+        create_mint_request_args(0, default_account(), amount),
+    );
+    builder.expect_success().commit();
+    let (request_id, digest) = expected_digest(0, default_account(), amount);
+    call_bridge(
+        &mut builder,
+        "approve_ceeth_mint",
+        runtime_args! { "request_id" => request_id, "payload_digest" => digest },
+    );
+    builder.expect_success().commit();
+    call_contract(
+        &mut builder,
+        &token_contract_key(),
+        "approve",
+        runtime_args! { "spender" => bridge_package, "amount" => amount },
+    );
+    builder.expect_success().commit();
+
+    // 以另一笔入站请求可预测的 ID 作为出站 tx_id
+    let victim = Key::Account(AccountHash::new([43u8; 32]));
+    let (victim_request_id, victim_digest) = expected_digest(1, victim, amount);
+    call_bridge(
+        &mut builder,
+        "burn_ceeth_for_eth",
+        runtime_args! {
+            "amount" => U256::from(100u64),
+            "tx_id" => victim_request_id.clone(),
+            "dst_chain" => SRC_CHAIN,
+            "eth_owner" => "0x1111111111111111111111111111111111111111",
+        },
+    );
+    builder.expect_success().commit();
+
+    // 入站请求不受影响
+    call_bridge(
+        &mut builder,
+        "create_mint_request",
+        create_mint_request_args(1, victim, amount),
+    );
+    builder.expect_success().commit();
+    call_bridge(
+        &mut builder,
+        "approve_ceeth_mint",
+        runtime_args! { "request_id" => victim_request_id, "payload_digest" => victim_digest },
+    );
+    builder.expect_success().commit();
+    assert_eq!(token_balance(&builder, token, victim), amount);
+}
+
+/// 创建并批准一笔铸造请求（单个守护节点即达到阈值）
+fn create_and_approve_mint(
+    builder: &mut LmdbWasmTestBuilder,
+    log_index: u64,
+    recipient: Key,
+    amount: U256,
+) {
+    call_bridge(
+        builder,
+        "create_mint_request",
+        create_mint_request_args(log_index, recipient, amount),
+    );
+    builder.expect_success().commit();
+    let (request_id, digest) = expected_digest(log_index, recipient, amount);
+    call_bridge(
+        builder,
+        "approve_ceeth_mint",
+        runtime_args! { "request_id" => request_id, "payload_digest" => digest },
+    );
+    builder.expect_success().commit();
+}
+
+#[test]
+fn requests_over_the_rate_limit_are_queued() {
+    let TestContext {
+        mut builder, token, ..
+    } = setup(true);
+    // 窗口足够长，测试期间额度不会恢复
+    call_bridge(
+        &mut builder,
+        "set_rate_limit",
+        runtime_args! {
+            "chain" => SRC_CHAIN,
+            "asset" => ASSET_CEETH,
+            "cap" => U256::from(1_000u64),
+            "window_ms" => 24 * 60 * 60 * 1000u64,
+        },
+    );
+    builder.expect_success().commit();
+
+    let first = Key::Account(AccountHash::new([42u8; 32]));
+    create_and_approve_mint(&mut builder, 0, first, U256::from(600u64));
+    assert_eq!(token_balance(&builder, token, first), U256::from(600u64));
+
+    // 剩余额度不足，达到阈值后进入待释放队列
+    let second = Key::Account(AccountHash::new([43u8; 32]));
+    create_and_approve_mint(&mut builder, 1, second, U256::from(500u64));
+    assert_eq!(token_balance(&builder, token, second), U256::zero());
+
+    let (request_id, _) = expected_digest(1, second, U256::from(500u64));
+    call_bridge(
+        &mut builder,
+        "execute_release",
+        runtime_args! { "request_id" => request_id },
+    );
+    assert_bridge_error(&mut builder, BridgeError::RateLimitExceeded);
+}
+
+#[test]
+fn oversized_request_is_queued_for_a_full_window() {
+    let TestContext {
+        mut builder, token, ..
+    } = setup(true);
+    call_bridge(
+        &mut builder,
+        "set_rate_limit",
+        runtime_args! {
+            "chain" => SRC_CHAIN,
+            "asset" => ASSET_CEETH,
+            "cap" => U256::from(100u64),
+            "window_ms" => 24 * 60 * 60 * 1000u64,
+        },
+    );
+    builder.expect_success().commit();
+
+    // 即使窗口内没有已用额度，超过 cap 的单笔请求也要排队
+    let whale = Key::Account(AccountHash::new([42u8; 32]));
+    let amount = U256::from(1_000u64);
+    create_and_approve_mint(&mut builder, 0, whale, amount);
+    assert_eq!(token_balance(&builder, token, whale), U256::zero());
+
+    // 延迟一个完整窗口之前不能执行
+    let (request_id, _) = expected_digest(0, whale, amount);
+    call_bridge(
+        &mut builder,
+        "execute_release",
+        runtime_args! { "request_id" => request_id },
+    );
+    assert_bridge_error(&mut builder, BridgeError::ReleaseNotReady);
+
+    // 排队的大额请求不占用额度，cap 内的请求照常放行
+    let next = Key::Account(AccountHash::new([43u8; 32]));
+    create_and_approve_mint(&mut builder, 1, next, U256::from(100u64));
+    assert_eq!(token_balance(&builder, token, next), U256::from(100u64));
+}
+
+#[test]
+fn approval_tiers_cannot_exceed_total_guardian_weight() {
+    let TestContext { mut builder, .. } = setup(true);
+    let tiers = |required_weight: u32| {
+        vec![ApprovalTier {
+            max_amount: U256::from(1_000u64),
+            required_weight,
+        }]
+    };
+
+    // 唯一的守护节点权重为 1
+    call_bridge(
+        &mut builder,
+        "set_approval_tiers",
+        runtime_args! { "asset" => ASSET_CEETH, "tiers" => tiers(2) },
+    );
+    assert_bridge_error(&mut builder, BridgeError::InvalidRequest);
+
+    call_bridge(
+        &mut builder,
+        "set_approval_tiers",
+        runtime_args! { "asset" => ASSET_CEETH, "tiers" => tiers(1) },
+    );
+    builder.expect_success().commit();
+}
+
+#[test]
+fn distinct_guardians_reach_a_supermajority_tier() {
+    let second = AccountHash::new([51u8; 32]);
+    let third = AccountHash::new([52u8; 32]);
+    let guardians = [*DEFAULT_ACCOUNT_ADDR, second, third]
+        .into_iter()
+        .map(|account| Guardian {
+            key: Key::Account(account),
+            weight: 1,
+        })
+        .collect();
+    let TestContext {
+        mut builder, token, ..
+    } = setup_with_guardians(true, guardians, 1);
+    fund_account(&mut builder, second);
+    fund_account(&mut builder, third);
+
+    // 1_000 以上的请求需要全部三个守护节点同意
+    call_bridge(
+        &mut builder,
+        "set_approval_tiers",
+        runtime_args! {
+            "asset" => ASSET_CEETH,
+            "tiers" => vec![
+                ApprovalTier { max_amount: U256::from(1_000u64), required_weight: 1 },
+                ApprovalTier { max_amount: U256::MAX, required_weight: 3 },
+            ],
+        },
+    );
+    builder.expect_success().commit();
+
+    let recipient = Key::Account(AccountHash::new([53u8; 32]));
+    let amount = U256::from(5_000u64);
+    call_bridge(
+        &mut builder,
+        "create_mint_request",
+        create_mint_request_args(0, recipient, amount),
+    );
+    builder.expect_success().commit();
+
+    let (request_id, digest) = expected_digest(0, recipient, amount);
+    let approve = || {
+        runtime_args! {
+            "request_id" => request_id.clone(),
+            "payload_digest" => digest.clone(),
+        }
+    };
+
+    // 管理员也只按自己的守护权重计票，重复投票不累加
+    call_bridge(&mut builder, "approve_ceeth_mint", approve());
+    builder.expect_success().commit();
+    call_bridge(&mut builder, "approve_ceeth_mint", approve());
+    builder.expect_success().commit();
+    call_bridge_as(&mut builder, second, "approve_ceeth_mint", approve());
+    builder.expect_success().commit();
+    assert_eq!(token_balance(&builder, token, recipient), U256::zero());
+
+    call_bridge_as(&mut builder, third, "approve_ceeth_mint", approve());
+    builder.expect_success().commit();
+    assert_eq!(token_balance(&builder, token, recipient), amount);
+
+    // 非守护节点不能投票
+    let outsider = AccountHash::new([54u8; 32]);
+    fund_account(&mut builder, outsider);
+    call_bridge_as(&mut builder, outsider, "approve_ceeth_mint", approve());
+    assert_bridge_error(&mut builder, BridgeError::PermissionDenied);
+}
+
+#[test]
+fn guardians_cancel_a_delayed_release_by_rejecting_it() {
+    let TestContext {
+        mut builder, token, ..
+    } = setup(true);
+    call_bridge(
+        &mut builder,
+        "set_release_delay",
+        runtime_args! {
+            "asset" => ASSET_CEETH,
+            "amount_threshold" => U256::zero(),
+            "delay_ms" => 24 * 60 * 60 * 1000u64,
+        },
+    );
+    builder.expect_success().commit();
+
+    let recipient = Key::Account(AccountHash::new([42u8; 32]));
+    let amount = U256::from(1_000u64);
+    create_and_approve_mint(&mut builder, 0, recipient, amount);
+    assert_eq!(token_balance(&builder, token, recipient), U256::zero());
+
+    let (request_id, _) = expected_digest(0, recipient, amount);
+    call_bridge(
+        &mut builder,
+        "cancel_release",
+        runtime_args! { "request_id" => request_id.clone() },
+    );
+    builder.expect_success().commit();
+
+    // 取消后条目被清空，无法再执行；请求标记为已拒绝，可作为罚没依据
+    call_bridge(
+        &mut builder,
+        "execute_release",
+        runtime_args! { "request_id" => request_id.clone() },
+    );
+    assert_bridge_error(&mut builder, BridgeError::InvalidRequest);
+    call_bridge(
+        &mut builder,
+        "slash_relayer",
+        runtime_args! { "relayer" => default_account(), "request_id" => request_id },
+    );
+    builder.expect_success().commit();
+    assert_eq!(token_balance(&builder, token, recipient), U256::zero());
+}
+
+#[test]
+fn mint_authorization_is_verified_against_the_token() {
+    let TestContext {
+        mut builder,
+        bridge_package,
+        ..
+    } = setup(false);
+    // 代币管理员撤销 Bridge 的 minter 权限
+    call_contract(
+        &mut builder,
+        &token_contract_key(),
+        "change_security",
+        runtime_args! { "none_list" => vec![bridge_package] },
+    );
+    builder.expect_success().commit();
+
+    call_bridge(
+        &mut builder,
+        "set_mint_authorized",
+        runtime_args! { "asset" => ASSET_CEETH, "authorized" => true },
+    );
+    builder.expect_failure();
+}
+
+#[test]
+fn installer_links_the_token_and_revokes_other_minters() {
+    let mut builder = LmdbWasmTestBuilder::default();
+    builder.run_genesis(LOCAL_GENESIS_REQUEST.clone()).commit();
+    let other_minter = AccountHash::new([61u8; 32]);
+    fund_account(&mut builder, other_minter);
+
+    // 代币先由同一 admin 部署，此时还有另一个 minter
+    let token = install_token(&mut builder, vec![Key::Account(other_minter)]);
+    let install_bridge = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        BRIDGE_WASM,
+        runtime_args! {
+            "admin" => default_account(),
+            "guardians" => vec![Guardian { key: default_account(), weight: 1 }],
+            "threshold" => 1u32,
+            "base_apr_bps" => 0u32,
+            "ceeth_token" => token,
+            "ceeth_revoked_minters" => vec![Key::Account(other_minter)],
+        },
+    )
+    .build();
+    builder.exec(install_bridge).expect_success().commit();
+    configure_bridge(&mut builder);
+
+    // 安装即完成链接与铸造授权
+    let recipient = Key::Account(AccountHash::new([62u8; 32]));
+    create_and_approve_mint(&mut builder, 0, recipient, U256::from(1_000u64));
+    assert_eq!(
+        token_balance(&builder, token, recipient),
+        U256::from(1_000u64)
+    );
+
+    // 部署者与原 minter 都已失去铸造权限
+    let mint_args = || runtime_args! { "owner" => recipient, "amount" => U256::one() };
+    call_contract(&mut builder, &token_contract_key(), "mint", mint_args());
+    builder.expect_failure();
+    let token_hash = token.into_hash_addr().expect("token contract hash");
+    let request = ExecuteRequestBuilder::contract_call_by_hash(
+        other_minter,
+        AddressableEntityHash::new(token_hash),
+        "mint",
+        mint_args(),
+    )
+    .build();
+    builder.exec(request).expect_failure();
+    assert_eq!(
+        token_balance(&builder, token, recipient),
+        U256::from(1_000u64)
+    );
+}
+
+#[test]
+fn fee_and_tip_are_minted_alongside_the_recipient_amount() {
+    let TestContext {
+        mut builder,
+        bridge_package,
+        token,
+    } = setup(true);
+    for (entry_point, args) in [
+        ("set_fee_bps", runtime_args! { "fee_bps" => 100u32 }),
+        (
+            "set_max_relayer_tip_bps",
+            runtime_args! { "tip_bps" => 1_000u32 },
+        ),
+    ] {
+        call_bridge(&mut builder, entry_point, args);
+        builder.expect_success().commit();
+    }
+
+    let recipient = Key::Account(AccountHash::new([42u8; 32]));
+    let amount = U256::from(10_000u64);
+    let relayer_tip = U256::from(500u64);
+    call_bridge(
+        &mut builder,
+        "create_mint_request",
+        create_mint_request_args_with_tip(0, recipient, amount, Some(relayer_tip)),
+    );
+    builder.expect_success().commit();
+
+    let (request_id, digest) = {
+        let mut req = request(0, recipient, amount);
+        req.relayer_tip = relayer_tip;
+        let digest = compute_payload_digest(&req).unwrap();
+        (req.id, digest)
+    };
+    call_bridge(
+        &mut builder,
+        "approve_ceeth_mint",
+        runtime_args! { "request_id" => request_id, "payload_digest" => digest },
+    );
+    builder.expect_success().commit();
+
+    // 1% 手续费铸造给 Bridge，小费铸造给提交请求的 Relayer
+    assert_eq!(
+        token_balance(&builder, token, recipient),
+        U256::from(9_400u64)
+    );
+    assert_eq!(
+        token_balance(&builder, token, default_account()),
+        relayer_tip
+    );
+    assert_eq!(
+        token_balance(&builder, token, bridge_package),
+        U256::from(100u64)
+    );
+}
+
+#[test]
+fn route_fee_limits_are_applied_in_asset_units() {
+    let TestContext {
+        mut builder,
+        bridge_package,
+        token,
+    } = setup(true);
+    // 费率为 0 时按路由上的最低手续费收取
+    call_bridge(
+        &mut builder,
+        "set_route_fee_limits",
+        runtime_args! {
+            "asset" => ASSET_CEETH,
+            "chain" => SRC_CHAIN,
+            "min_fee" => U256::from(300u64),
+            "max_fee" => U256::zero(),
+        },
+    );
+    builder.expect_success().commit();
+
+    let recipient = Key::Account(AccountHash::new([42u8; 32]));
+    create_and_approve_mint(&mut builder, 0, recipient, U256::from(10_000u64));
+    assert_eq!(
+        token_balance(&builder, token, recipient),
+        U256::from(9_700u64)
+    );
+    assert_eq!(
+        token_balance(&builder, token, bridge_package),
+        U256::from(300u64)
+    );
+}
+
+#[test]
+fn inbound_tip_above_the_cap_is_clamped() {
+    let TestContext {
+        mut builder, token, ..
+    } = setup(true);
+    let recipient = Key::Account(AccountHash::new([42u8; 32]));
+    let amount = U256::from(1_000u64);
+
+    // 未配置小费上限（默认 0），源链事件中的小费截断为 0，全额归接收人
+    call_bridge(
+        &mut builder,
+        "create_mint_request",
+        create_mint_request_args_with_tip(0, recipient, amount, Some(U256::from(100u64))),
+    );
+    builder.expect_success().commit();
+
+    let (request_id, digest) = expected_digest(0, recipient, amount);
+    call_bridge(
+        &mut builder,
+        "approve_ceeth_mint",
+        runtime_args! { "request_id" => request_id, "payload_digest" => digest },
+    );
+    builder.expect_success().commit();
+    assert_eq!(token_balance(&builder, token, recipient), amount);
+}
+
+#[test]
+fn protocol_reserve_withdrawal_is_bounded_by_the_reserve() {
+    let TestContext { mut builder, .. } = setup(false);
+    // 尚未产生业绩分成，储备为 0
+    call_bridge(
+        &mut builder,
+        "withdraw_protocol_reserve",
+        runtime_args! { "amount" => U256::one(), "recipient" => default_account() },
+    );
+    assert_bridge_error(&mut builder, BridgeError::InsufficientFunds);
+}
+
+#[test]
+fn init_cannot_be_called_again() {
+    let TestContext { mut builder, .. } = setup(false);
+    let attacker = Key::Account(AccountHash::new([66u8; 32]));
+    call_bridge(
+        &mut builder,
+        "init",
+        runtime_args! {
+            "admin" => attacker,
+            "guardians" => vec![Guardian { key: attacker, weight: 1 }],
+            "threshold" => 1u32,
+            "base_apr_bps" => 0u32,
+        },
+    );
+    assert_bridge_error(&mut builder, BridgeError::PermissionDenied);
+}
@@ -0,0 +1,104 @@
+//! Every registered entry point must be exported by the contract wasm.
+//!
+//! 测试自行以当前源码编译 wasm（需安装 wasm32-unknown-unknown target），
+//! 也可通过 `BRIDGE_CORE_WASM` 指定已编译的 wasm 路径。
+
+use std::{collections::BTreeSet, path::PathBuf, process::Command};
+
+use bridge_core::ENTRY_POINT_NAMES;
+
+const MANIFEST_DIR: &str = env!("CARGO_MANIFEST_DIR");
+const EXPORT_SECTION: u8 = 7;
+const EXPORT_KIND_FUNC: u8 = 0;
+
+fn read_leb128(bytes: &[u8], offset: &mut usize) -> u32 {
+    let mut value = 0u32;
+    let mut shift = 0;
+    loop {
+        let byte = bytes[*offset];
+        *offset += 1;
+        value |= u32::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return value;
+        }
+        shift += 7;
+    }
+}
+
+/// 以当前源码编译合约 wasm 并返回其路径
+///
+/// 使用独立的 target 目录，避免与测试进程自身的构建争用 cargo 锁
+fn build_bridge_wasm() -> PathBuf {
+    if let Ok(path) = std::env::var("BRIDGE_CORE_WASM") {
+        return PathBuf::from(path);
+    }
+    let target_dir = PathBuf::from(MANIFEST_DIR).join("target/wasm-export-check");
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".into());
+    let status = Command::new(cargo)
+        .current_dir(MANIFEST_DIR)
+        .args([
+            "build",
+            "--release",
+            "--target",
+            "wasm32-unknown-unknown",
+            "--lib",
+        ])
+        .arg("--target-dir")
+        .arg(&target_dir)
+        .status()
+        .expect("failed to run cargo");
+    assert!(status.success(), "building bridge_core.wasm failed");
+    target_dir.join("wasm32-unknown-unknown/release/bridge_core.wasm")
+}
+
+/// 解析 wasm 导出段中的函数名
+fn exported_functions(wasm: &[u8]) -> BTreeSet<String> {
+    assert_eq!(&wasm[..4], b"\0asm", "not a wasm module");
+    let mut exports = BTreeSet::new();
+    let mut offset = 8;
+    while offset < wasm.len() {
+        let id = wasm[offset];
+        offset += 1;
+        let size = read_leb128(wasm, &mut offset) as usize;
+        let end = offset + size;
+        if id == EXPORT_SECTION {
+            let count = read_leb128(wasm, &mut offset);
+            for _ in 0..count {
+                let len = read_leb128(wasm, &mut offset) as usize;
+                let name = String::from_utf8(wasm[offset..offset + len].to_vec()).unwrap();
+                offset += len;
+                let kind = wasm[offset];
+                offset += 1;
+                read_leb128(wasm, &mut offset);
+                if kind == EXPORT_KIND_FUNC {
+                    exports.insert(name);
+                }
+            }
+        }
+        offset = end;
+    }
+    exports
+}
+
+#[test]
+fn every_registered_entry_point_is_exported() {
+    let wasm = std::fs::read(build_bridge_wasm()).expect("bridge_core.wasm not found");
+    let exports = exported_functions(&wasm);
+
+    assert!(exports.contains("call"), "installer `call` not exported");
+    let missing: Vec<&str> = ENTRY_POINT_NAMES
+        .iter()
+        .copied()
+        .filter(|name| !exports.contains(*name))
+        .collect();
+    assert!(
+        missing.is_empty(),
+        "entry points without export: {missing:?}"
+    );
+}
+
+#[test]
+fn entry_point_names_are_unique() {
+    let unique: BTreeSet<&str> = ENTRY_POINT_NAMES.iter().copied().collect();
+    assert_eq!(unique.len(), ENTRY_POINT_NAMES.len());
+}
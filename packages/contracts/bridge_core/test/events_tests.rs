@@ -0,0 +1,438 @@
+//! Round-trip tests for the event envelope.
+
+use std::collections::BTreeSet;
+
+use bridge_core::*;
+use casper_types::{
+    account::AccountHash,
+    bytesrepr::{FromBytes, ToBytes},
+    Key, U256,
+};
+
+const REQUEST_ID: &str = "5f1c0d7cbb1e4a2f9e8a7b6c5d4e3f2a1b0c9d8e7f6a5b4c3d2e1f0a9b8c7d6e";
+
+fn account(seed: u8) -> Key {
+    Key::Account(AccountHash::new([seed; 32]))
+}
+
+fn token() -> Key {
+    Key::Hash([9u8; 32])
+}
+
+fn chain_config() -> ChainConfig {
+    ChainConfig {
+        id: "ethereum".into(),
+        evm_chain_id: 1,
+        address_format: ADDRESS_FORMAT_EVM,
+        decimals: 18,
+        enabled: true,
+    }
+}
+
+fn asset_config() -> AssetConfig {
+    AssetConfig {
+        id: ASSET_CEETH.into(),
+        token: Some(token()),
+        decimals: CEETH_DECIMALS,
+        mode: ASSET_MODE_MINT_BURN,
+        enabled: true,
+    }
+}
+
+fn asset_route(enabled: bool) -> AssetRoute {
+    AssetRoute {
+        asset: ASSET_CEETH.into(),
+        chain: "ethereum".into(),
+        remote_token: vec![0u8; 20],
+        remote_decimals: 18,
+        enabled,
+        min_amount: U256::one(),
+        max_amount: U256::zero(),
+        min_fee: U256::zero(),
+        max_fee: U256::zero(),
+    }
+}
+
+fn tiers() -> Vec<ApprovalTier> {
+    vec![ApprovalTier {
+        max_amount: U256::from(1_000u64),
+        required_weight: 2,
+    }]
+}
+
+/// 封装、序列化、反序列化并解码，返回事件的判别值
+fn round_trip<T>(event: T) -> u8
+where
+    T: BridgeEvent + Clone + PartialEq + std::fmt::Debug,
+    AnyEvent: From<T>,
+{
+    let envelope = EventEnvelope::seal(7, &event);
+    let bytes = envelope.to_bytes().unwrap();
+    let (decoded, rest) = EventEnvelope::from_bytes(&bytes).unwrap();
+    assert!(rest.is_empty());
+    assert_eq!(decoded, envelope);
+    assert_eq!(decoded.nonce, 7);
+    assert_eq!(decoded.version, T::VERSION);
+
+    assert_eq!(decoded.decode::<T>(), Some(event.clone()));
+    assert_eq!(AnyEvent::open(&decoded), Some(AnyEvent::from(event)));
+    T::DISCRIMINANT
+}
+
+#[test]
+fn every_event_round_trips_through_envelope() {
+    let mut seen = Vec::new();
+    seen.push(round_trip(UnlockRequested {
+        request_id: REQUEST_ID.into(),
+        recipient: account(2),
+        amount: U256::from(1_000_000_000u64),
+        remote_amount: U256::from(1_000_000_000_000_000_000u128),
+        asset: "ceeth".into(),
+        src_chain: "ethereum".into(),
+        src_tx_hash: "0xabc1".into(),
+        log_index: 3,
+        dst_chain: "ethereum".into(),
+        relayer_tip: U256::from(500u64),
+        submitter: account(3),
+        required_weight: 2,
+    }));
+    seen.push(round_trip(UnlockFinalized {
+        request_id: REQUEST_ID.into(),
+        recipient: account(2),
+        asset: "ceeth".into(),
+        amount: U256::from(1_000_000_000u64),
+        fee: U256::from(3_000u64),
+        relayer_tip: U256::from(500u64),
+        submitter: account(3),
+    }));
+    seen.push(round_trip(HotSwapProposed {
+        patch_hash: "patch-1".into(),
+        proposer: account(4),
+    }));
+    seen.push(round_trip(HotSwapActivated {
+        patch_hash: "patch-1".into(),
+    }));
+    seen.push(round_trip(PauseChanged { paused: true }));
+    seen.push(round_trip(YieldAccrued {
+        account: account(2),
+        principal_after: U256::from(1_000_000_000u64),
+        gross_interest: U256::from(1_000_000_000u64),
+        fee: U256::from(3_000u64),
+        net_interest: U256::from(1_000_000_000u64),
+    }));
+    seen.push(round_trip(CsprLockedForTarget {
+        sender: account(1),
+        amount: U256::from(1_000_000_000u64),
+        dst_chain: "ethereum".into(),
+        tx_id: "0xabc1".into(),
+        recipient: vec![0xab; 20],
+        fee: U256::from(3_000u64),
+        relayer_tip: U256::from(500u64),
+        remote_amount: U256::from(1_000_000_000_000_000_000u128),
+        remote_relayer_tip: U256::from(500_000_000_000u64),
+    }));
+    seen.push(round_trip(CeETHMinted {
+        recipient: account(2),
+        asset: "ceeth".into(),
+        amount: U256::from(1_000_000_000u64),
+        fee: U256::from(3_000u64),
+        relayer_tip: U256::from(500u64),
+        submitter: account(3),
+        tx_id: "0xabc1".into(),
+    }));
+    seen.push(round_trip(CeETHBurned {
+        eth_owner: vec![0xab; 20],
+        dst_chain: "ethereum".into(),
+        amount: U256::from(1_000_000_000u64),
+        remote_amount: U256::from(1_000_000_000_000_000_000u128),
+        tx_id: "0xabc1".into(),
+    }));
+    seen.push(round_trip(RewardReserveFunded {
+        funder: account(1),
+        amount: U256::from(1_000_000_000u64),
+        reserve_after: U256::from(1_000_000_000u64),
+    }));
+    seen.push(round_trip(RewardReserveLow {
+        reserve: U256::from(1_000_000_000u64),
+        watermark: U256::from(1_000_000_000u64),
+        uncovered_interest: U256::from(1_000_000_000u64),
+    }));
+    seen.push(round_trip(FeesWithdrawn {
+        asset: "ceeth".into(),
+        recipient: account(2),
+        amount: U256::from(1_000_000_000u64),
+    }));
+    seen.push(round_trip(RelayerRegistered {
+        relayer: account(3),
+        bond: U256::from(1_000_000_000u64),
+    }));
+    seen.push(round_trip(RelayerUnbonding {
+        relayer: account(3),
+        bond: U256::from(1_000_000_000u64),
+        unbonding_at_ms: 86_400_000,
+    }));
+    seen.push(round_trip(RelayerSlashed {
+        relayer: account(3),
+        request_id: REQUEST_ID.into(),
+        amount: U256::from(1_000_000_000u64),
+    }));
+    seen.push(round_trip(RequestConflict {
+        request_id: REQUEST_ID.into(),
+        existing_digest: "aa".into(),
+        proposed_digest: "bb".into(),
+        submitter: account(3),
+    }));
+    seen.push(round_trip(AssetLocked {
+        asset: "ceeth".into(),
+        sender: account(1),
+        dst_chain: "ethereum".into(),
+        recipient: vec![0xab; 20],
+        remote_token: vec![0u8; 20],
+        amount: U256::from(1_000_000_000u64),
+        fee: U256::from(3_000u64),
+        relayer_tip: U256::from(500u64),
+        remote_amount: U256::from(1_000_000_000_000_000_000u128),
+        remote_relayer_tip: U256::from(500_000_000_000u64),
+        tx_id: "0xabc1".into(),
+    }));
+    seen.push(round_trip(AssetBurned {
+        asset: "ceeth".into(),
+        sender: account(1),
+        dst_chain: "ethereum".into(),
+        recipient: vec![0xab; 20],
+        remote_token: vec![0u8; 20],
+        amount: U256::from(1_000_000_000u64),
+        remote_amount: U256::from(1_000_000_000_000_000_000u128),
+        tx_id: "0xabc1".into(),
+    }));
+    seen.push(round_trip(ReleaseQueued {
+        request_id: REQUEST_ID.into(),
+        src_chain: "ethereum".into(),
+        asset: "ceeth".into(),
+        amount: U256::from(1_000_000_000u64),
+        remaining_capacity: U256::from(1_000_000_000u64),
+        release_after_ms: 86_400_000,
+    }));
+    seen.push(round_trip(ReleaseCancelled {
+        request_id: REQUEST_ID.into(),
+        guardian: account(5),
+    }));
+    seen.push(round_trip(BridgeInitialized {
+        admin: account(4),
+        guardian_count: 3,
+        threshold: 2,
+        base_apr_bps: 500,
+    }));
+    seen.push(round_trip(GuardianVoted {
+        subject: REQUEST_ID.into(),
+        kind: VOTE_KIND_UNLOCK,
+        guardian: account(5),
+        weight: 1,
+        approvals_weight: 1,
+        required_weight: 2,
+    }));
+    seen.push(round_trip(AprUpdated {
+        old_apr_bps: 500,
+        new_apr_bps: 600,
+    }));
+    seen.push(round_trip(AdminTransferred {
+        old_admin: account(4),
+        new_admin: account(6),
+    }));
+    seen.push(round_trip(TreasurerChanged {
+        old_treasurer: account(4),
+        new_treasurer: account(7),
+    }));
+    seen.push(round_trip(FeeBpsUpdated {
+        old_fee_bps: 0,
+        new_fee_bps: 30,
+    }));
+    seen.push(round_trip(PerformanceFeeBpsUpdated {
+        old_fee_bps: 0,
+        new_fee_bps: 30,
+    }));
+    seen.push(round_trip(RouteFeeLimitsUpdated {
+        asset: ASSET_CEETH.into(),
+        chain: "ethereum".into(),
+        old_min_fee: U256::from(1_000_000_000u64),
+        old_max_fee: U256::from(1_000_000_000u64),
+        new_min_fee: U256::from(1_000_000_000u64),
+        new_max_fee: U256::from(1_000_000_000u64),
+    }));
+    seen.push(round_trip(ReserveWatermarkUpdated {
+        old_watermark: U256::from(1_000_000_000u64),
+        new_watermark: U256::from(1_000_000_000u64),
+    }));
+    seen.push(round_trip(RelayerBondConfigUpdated {
+        old_min_bond: U256::from(1_000_000_000u64),
+        new_min_bond: U256::from(1_000_000_000u64),
+        old_unbonding_ms: 0,
+        new_unbonding_ms: 86_400_000,
+    }));
+    seen.push(round_trip(RelayerBondWithdrawn {
+        relayer: account(3),
+        amount: U256::from(1_000_000_000u64),
+    }));
+    seen.push(round_trip(ChainUpdated {
+        id: "ethereum".into(),
+        old: None,
+        new: chain_config(),
+    }));
+    seen.push(round_trip(AssetUpdated {
+        id: "ethereum".into(),
+        old: None,
+        new: asset_config(),
+    }));
+    seen.push(round_trip(AssetRouteUpdated {
+        asset: "ceeth".into(),
+        chain: "ethereum".into(),
+        old: Some(asset_route(false)),
+        new: asset_route(true),
+    }));
+    seen.push(round_trip(RateLimitUpdated {
+        chain: "ethereum".into(),
+        asset: "ceeth".into(),
+        old_cap: U256::from(1_000_000_000u64),
+        new_cap: U256::from(1_000_000_000u64),
+        old_window_ms: 0,
+        new_window_ms: 86_400_000,
+    }));
+    seen.push(round_trip(ReleaseDelayUpdated {
+        asset: "ceeth".into(),
+        old_amount_threshold: U256::from(1_000_000_000u64),
+        new_amount_threshold: U256::from(1_000_000_000u64),
+        old_delay_ms: 0,
+        new_delay_ms: 3_600_000,
+    }));
+    seen.push(round_trip(ApprovalTiersUpdated {
+        asset: "ceeth".into(),
+        old: vec![],
+        new: tiers(),
+    }));
+    seen.push(round_trip(MintAuthorizationChanged {
+        asset: "ceeth".into(),
+        old_authorized: false,
+        new_authorized: true,
+    }));
+    seen.push(round_trip(CeETHTokenSet {
+        old_token: None,
+        new_token: token(),
+    }));
+    seen.push(round_trip(MintRequested {
+        request_id: REQUEST_ID.into(),
+        recipient: account(2),
+        amount: U256::from(1_000_000_000u64),
+        remote_amount: U256::from(1_000_000_000_000_000_000u128),
+        asset: "ceeth".into(),
+        src_chain: "ethereum".into(),
+        src_tx_hash: "0xabc1".into(),
+        log_index: 3,
+        dst_chain: "ethereum".into(),
+        relayer_tip: U256::from(500u64),
+        submitter: account(3),
+        required_weight: 2,
+    }));
+    seen.push(round_trip(PositionWithdrawn {
+        account: account(2),
+        amount: U256::from(1_000u64),
+        principal_after: U256::from(500u64),
+    }));
+    seen.push(round_trip(MaxRelayerTipBpsUpdated {
+        old_tip_bps: 0,
+        new_tip_bps: 50,
+    }));
+    seen.push(round_trip(RequestRejected {
+        request_id: REQUEST_ID.into(),
+        asset: "ceeth".into(),
+        payload_digest: "ab".repeat(32),
+        submitter: account(3),
+        rejections_weight: 2,
+    }));
+    seen.push(round_trip(AssetMinted {
+        asset: "wbtc".into(),
+        recipient: account(2),
+        amount: U256::from(990u64),
+        fee: U256::from(10u64),
+        relayer_tip: U256::zero(),
+        submitter: account(3),
+        request_id: REQUEST_ID.into(),
+    }));
+    seen.push(round_trip(ProtocolReserveWithdrawn {
+        recipient: account(1),
+        amount: U256::from(1_000u64),
+        reserve_after: U256::from(500u64),
+    }));
+
+    // 判别值互不重复
+    let unique: BTreeSet<u8> = seen.iter().copied().collect();
+    assert_eq!(unique.len(), seen.len());
+}
+
+#[test]
+fn unknown_versions_and_events_are_skipped() {
+    let event = PauseChanged { paused: true };
+    let envelope = EventEnvelope::seal(1, &event);
+
+    // 旧版本 Relayer 遇到更高版本时跳过，而不是按旧字段误读
+    let newer = EventEnvelope {
+        version: PauseChanged::VERSION + 1,
+        ..envelope.clone()
+    };
+    assert_eq!(newer.decode::<PauseChanged>(), None);
+    assert_eq!(AnyEvent::open(&newer), None);
+
+    // 未知的事件类型
+    let unknown = EventEnvelope {
+        discriminant: u8::MAX,
+        ..envelope.clone()
+    };
+    assert_eq!(AnyEvent::open(&unknown), None);
+
+    // 判别值与目标类型不一致
+    assert_eq!(envelope.decode::<HotSwapActivated>(), None);
+}
+
+#[test]
+fn message_payload_decodes_to_event() {
+    let event = PauseChanged { paused: true };
+    let envelope = EventEnvelope::seal(3, &event);
+    let message = hex::encode(envelope.to_bytes().unwrap());
+
+    assert_eq!(EventEnvelope::from_hex(&message).unwrap(), envelope);
+    assert_eq!(
+        AnyEvent::from_message(&message),
+        Some(AnyEvent::PauseChanged(event))
+    );
+    assert_eq!(AnyEvent::from_message("not hex"), None);
+
+    // 与 CES 布局一致：事件名在前，字段随后
+    let (name, rest) = String::from_bytes(&envelope.payload).unwrap();
+    assert_eq!(name, "event_PauseChanged");
+    assert_eq!(rest, &[1u8][..]);
+}
+
+#[test]
+fn event_bytes_match_ces_layout() {
+    // 合约构建（CES 派生）与 client 构建（手工实现）都必须得到同样的字节
+    let event = UnlockFinalized {
+        request_id: REQUEST_ID.into(),
+        recipient: account(1),
+        asset: ASSET_CEETH.into(),
+        amount: U256::from(9_400u64),
+        fee: U256::from(100u64),
+        relayer_tip: U256::from(500u64),
+        submitter: account(2),
+    };
+
+    let mut expected = String::from("event_UnlockFinalized").to_bytes().unwrap();
+    expected.extend(event.request_id.to_bytes().unwrap());
+    expected.extend(event.recipient.to_bytes().unwrap());
+    expected.extend(event.asset.to_bytes().unwrap());
+    expected.extend(event.amount.to_bytes().unwrap());
+    expected.extend(event.fee.to_bytes().unwrap());
+    expected.extend(event.relayer_tip.to_bytes().unwrap());
+    expected.extend(event.submitter.to_bytes().unwrap());
+
+    assert_eq!(event.to_bytes().unwrap(), expected);
+    assert_eq!(event.serialized_length(), expected.len());
+}
@@ -1,7 +1,12 @@
 //! Integration tests for interest calculation.
 
-use bridge_core::utils::compute_yield;
-use casper_types::U256;
+use bridge_core::{
+    canonical_tx_hash, chain_outbound_root, check_supply_invariants, compute_fee,
+    compute_message_id, compute_yield, decay_rate_limit_usage, normalize_tx_hash,
+    rate_limit_admits, required_approval_weight, scale_amount, scale_amount_floor, ApprovalTier,
+    AssetReserves, BridgeError,
+};
+use casper_types::{account::AccountHash, Key, U256};
 
 #[test]
 fn zero_inputs_yield_zero() {
@@ -14,8 +19,276 @@ fn zero_inputs_yield_zero() {
 fn accrues_proportionally() {
     // 5% APR，半年利息约 25（整除向下取整）
     let principal = U256::from(1_000u64);
-    let apr_bps: u16 = 500; // 5%
+    let apr_bps: u32 = 500; // 5%
     let half_year_ms: u64 = 365 / 2 * 24 * 60 * 60 * 1000;
     let interest = compute_yield(principal, apr_bps, half_year_ms);
     assert_eq!(interest, U256::from(25u64));
 }
+
+#[test]
+fn yield_above_u128_does_not_panic() {
+    let principal = U256::from(u128::MAX) * U256::from(1_000u64);
+    let year_ms: u64 = 365 * 24 * 60 * 60 * 1000;
+    // 10% APR，一年利息为本金的十分之一
+    assert_eq!(compute_yield(principal, 1_000, year_ms), principal / 10);
+}
+
+#[test]
+fn fee_is_clamped_to_chain_limits() {
+    let amount = U256::from(1_000_000u64);
+    // 0.3% => 3_000
+    assert_eq!(
+        compute_fee(amount, 30, U256::zero(), U256::zero()),
+        U256::from(3_000u64)
+    );
+    // 低于下限按下限收取
+    assert_eq!(
+        compute_fee(amount, 30, U256::from(5_000u64), U256::zero()),
+        U256::from(5_000u64)
+    );
+    // 高于上限按上限收取
+    assert_eq!(
+        compute_fee(amount, 30, U256::zero(), U256::from(1_000u64)),
+        U256::from(1_000u64)
+    );
+    // 手续费不会超过金额本身
+    assert_eq!(
+        compute_fee(U256::from(10u64), 30, U256::from(50u64), U256::zero()),
+        U256::from(10u64)
+    );
+}
+
+const TX_HASH: &str = "0x5f1c0d7cbb1e4a2f9e8a7b6c5d4e3f2a1b0c9d8e7f6a5b4c3d2e1f0a9b8c7d6e";
+
+#[test]
+fn message_id_is_bound_to_source_event() {
+    let recipient = Key::Account(AccountHash::new([7u8; 32]));
+    let amount = U256::from(1_000u64);
+    let id = compute_message_id("ethereum", TX_HASH, 3, &recipient, amount, "ceeth").unwrap();
+
+    assert_eq!(id.len(), 64);
+    // 任一字段变化都会得到不同的 ID
+    assert_ne!(
+        id,
+        compute_message_id("ethereum", TX_HASH, 4, &recipient, amount, "ceeth").unwrap()
+    );
+    assert_ne!(
+        id,
+        compute_message_id(
+            "ethereum",
+            TX_HASH,
+            3,
+            &recipient,
+            U256::from(999u64),
+            "ceeth"
+        )
+        .unwrap()
+    );
+}
+
+#[test]
+fn message_id_ignores_tx_hash_prefix_and_case() {
+    let recipient = Key::Account(AccountHash::new([7u8; 32]));
+    let amount = U256::from(1_000u64);
+    let id =
+        |tx_hash: &str| compute_message_id("ethereum", tx_hash, 3, &recipient, amount, "ceeth");
+
+    let lower = &TX_HASH[2..];
+    let upper = lower.to_ascii_uppercase();
+    let expected = id(TX_HASH).unwrap();
+    assert_eq!(id(lower).unwrap(), expected);
+    assert_eq!(id(&upper).unwrap(), expected);
+    assert_eq!(id(&format!("0x{upper}")).unwrap(), expected);
+    assert_eq!(id(&format!("0X{lower}")).unwrap(), expected);
+
+    assert_eq!(canonical_tx_hash(&upper).unwrap(), TX_HASH);
+    assert_eq!(normalize_tx_hash(lower).unwrap()[0], 0x5f);
+}
+
+#[test]
+fn malformed_tx_hashes_are_rejected() {
+    let recipient = Key::Account(AccountHash::new([7u8; 32]));
+    let amount = U256::from(1_000u64);
+    let too_long = format!("{TX_HASH}00");
+    let not_hex = TX_HASH.replace('5', "g");
+    let cases: [&str; 6] = ["", "0x", "0xabc1", &TX_HASH[..64], &too_long, &not_hex];
+    for tx_hash in cases {
+        assert_eq!(
+            compute_message_id("ethereum", tx_hash, 3, &recipient, amount, "ceeth"),
+            Err(BridgeError::InvalidTxHash),
+            "{tx_hash}"
+        );
+    }
+}
+
+#[test]
+fn scales_amounts_between_decimals() {
+    // 1 CSPR (9 位) <-> 1 个 18 位精度单位
+    let one_cspr = U256::from(1_000_000_000u64);
+    let one_eth = U256::from(1_000_000_000_000_000_000u128);
+    assert_eq!(scale_amount(one_cspr, 9, 18), Ok(one_eth));
+    assert_eq!(scale_amount(one_eth, 18, 9), Ok(one_cspr));
+    assert_eq!(scale_amount(one_cspr, 9, 9), Ok(one_cspr));
+
+    // 低精度无法表示的尾数被拒绝
+    assert_eq!(
+        scale_amount(one_eth + U256::one(), 18, 9),
+        Err(BridgeError::DustAmount)
+    );
+    // 放大溢出
+    assert_eq!(
+        scale_amount(U256::MAX, 0, 18),
+        Err(BridgeError::InvalidAmount)
+    );
+}
+
+#[test]
+fn floor_scaling_drops_dust() {
+    let one_cspr = U256::from(1_000_000_000u64);
+    let one_eth = U256::from(1_000_000_000_000_000_000u128);
+    // 缩小时舍去尾数而不是拒绝
+    assert_eq!(
+        scale_amount_floor(one_eth + U256::from(999_999_999u64), 18, 9),
+        Ok(one_cspr)
+    );
+    assert_eq!(scale_amount_floor(U256::one(), 18, 9), Ok(U256::zero()));
+    // 放大与精确缩放与 scale_amount 一致
+    assert_eq!(scale_amount_floor(one_cspr, 9, 18), Ok(one_eth));
+    assert_eq!(
+        scale_amount_floor(U256::MAX, 0, 18),
+        Err(BridgeError::InvalidAmount)
+    );
+}
+
+#[test]
+fn rate_limit_usage_recovers_over_window() {
+    let cap = U256::from(1_000u64);
+    let day_ms: u64 = 24 * 60 * 60 * 1000;
+
+    // 未经过时间不恢复
+    assert_eq!(
+        decay_rate_limit_usage(cap, cap, day_ms, 0),
+        U256::from(1_000u64)
+    );
+    // 半个窗口恢复一半额度
+    assert_eq!(
+        decay_rate_limit_usage(cap, cap, day_ms, day_ms / 2),
+        U256::from(500u64)
+    );
+    // 恢复量不会让已用额度变为负数
+    assert_eq!(
+        decay_rate_limit_usage(U256::from(100u64), cap, day_ms, day_ms / 2),
+        U256::zero()
+    );
+    // 超过整个窗口全部恢复
+    assert_eq!(
+        decay_rate_limit_usage(cap, cap, day_ms, day_ms),
+        U256::zero()
+    );
+}
+
+#[test]
+fn rate_limit_admits_only_within_cap() {
+    let cap = U256::from(1_000u64);
+
+    assert!(rate_limit_admits(
+        U256::from(400u64),
+        cap,
+        U256::from(600u64)
+    ));
+    assert!(!rate_limit_admits(
+        U256::from(401u64),
+        cap,
+        U256::from(600u64)
+    ));
+    // 单笔超过 cap 的请求即使窗口为空也不放行
+    assert!(!rate_limit_admits(U256::zero(), cap, U256::from(5_000u64)));
+    // 未配置限流
+    assert!(rate_limit_admits(U256::MAX, U256::zero(), U256::MAX));
+}
+
+#[test]
+fn approval_weight_follows_amount_tiers() {
+    let tiers = vec![
+        ApprovalTier {
+            max_amount: U256::from(1_000u64),
+            required_weight: 2,
+        },
+        ApprovalTier {
+            max_amount: U256::from(1_000_000u64),
+            required_weight: 5,
+        },
+    ];
+
+    assert_eq!(required_approval_weight(U256::from(10u64), &tiers, 3), 3);
+    assert_eq!(required_approval_weight(U256::from(1_000u64), &tiers, 1), 2);
+    assert_eq!(required_approval_weight(U256::from(1_001u64), &tiers, 1), 5);
+    // 超过所有档位按最高档
+    assert_eq!(
+        required_approval_weight(U256::from(5_000_000u64), &tiers, 1),
+        5
+    );
+    // 未配置档位时使用基础阈值
+    assert_eq!(
+        required_approval_weight(U256::from(5_000_000u64), &[], 3),
+        3
+    );
+}
+
+#[test]
+fn supply_invariants_require_attested_backing() {
+    let reserves = AssetReserves {
+        total_minted: U256::from(1_000u64),
+        total_burned: U256::from(400u64),
+        attested_remote_locked: U256::from(1_000u64),
+        ..Default::default()
+    };
+    assert_eq!(check_supply_invariants(&reserves, None), Ok(()));
+    // 流通量 = 已确认 - 已销毁
+    assert_eq!(
+        check_supply_invariants(&reserves, Some(U256::from(600u64))),
+        Ok(())
+    );
+
+    // 代币合约上存在 Bridge 之外铸造的流通量
+    assert_eq!(
+        check_supply_invariants(&reserves, Some(U256::from(601u64))),
+        Err(BridgeError::InvariantViolated)
+    );
+    // 铸造超过守护节点确认的远端锁仓
+    let over_minted = AssetReserves {
+        total_minted: U256::from(1_001u64),
+        ..reserves.clone()
+    };
+    assert_eq!(
+        check_supply_invariants(&over_minted, None),
+        Err(BridgeError::InvariantViolated)
+    );
+    // 释放超过锁入
+    let over_released = AssetReserves {
+        total_locked: U256::from(10u64),
+        total_released: U256::from(11u64),
+        ..Default::default()
+    };
+    assert_eq!(
+        check_supply_invariants(&over_released, None),
+        Err(BridgeError::InvariantViolated)
+    );
+}
+
+#[test]
+fn outbound_root_chains_every_event() {
+    let genesis = [0u8; 32];
+    let first = chain_outbound_root(&genesis, 0, b"lock");
+    let second = chain_outbound_root(&first, 1, b"burn");
+
+    assert_ne!(first, genesis);
+    // 结果只取决于链头、序号与事件字节
+    assert_eq!(first, chain_outbound_root(&genesis, 0, b"lock"));
+    // 序号或顺序不同都会得到不同的链头
+    assert_ne!(first, chain_outbound_root(&genesis, 1, b"lock"));
+    assert_ne!(
+        second,
+        chain_outbound_root(&chain_outbound_root(&genesis, 0, b"burn"), 1, b"lock")
+    );
+}
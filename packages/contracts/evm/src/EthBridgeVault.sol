@@ -12,27 +12,47 @@ contract EthBridgeVault is AccessControl, ReentrancyGuard {
     /// 防重放：记录 depositId / burnTx 是否已处理
     mapping(bytes32 => bool) public processed;
 
+    /// Relayer 小费上限 (bps)，应与 Casper 侧 max_relayer_tip_bps 一致；默认 0 不允许小费
+    uint256 public maxRelayerTipBps;
+
     event EthLocked(
-        address indexed sender, uint256 amount, bytes32 indexed depositId, string dstChain, string dstAccount
+        address indexed sender,
+        uint256 amount,
+        bytes32 indexed depositId,
+        string dstChain,
+        string dstAccount,
+        uint256 relayerTip
     );
 
     event EthReleased(address indexed recipient, uint256 amount, bytes32 indexed burnTx);
 
+    event MaxRelayerTipBpsUpdated(uint256 oldTipBps, uint256 newTipBps);
+
     constructor(address admin_) {
         _grantRole(DEFAULT_ADMIN_ROLE, admin_);
         _grantRole(RELAYER_ROLE, admin_);
     }
 
+    /// @dev 管理员设置小费上限
+    function setMaxRelayerTipBps(uint256 tipBps) external onlyRole(DEFAULT_ADMIN_ROLE) {
+        require(tipBps <= 10_000, "Vault: invalid tip bps");
+        emit MaxRelayerTipBpsUpdated(maxRelayerTipBps, tipBps);
+        maxRelayerTipBps = tipBps;
+    }
+
     /// @dev 用户锁定 ETH，depositId 应由前端/桥生成并全局唯一
-    function lockEth(string calldata dstChain, string calldata dstAccount, bytes32 depositId)
+    /// relayerTip 从锁定金额中扣除，在 Casper 侧完成时支付给提交请求的 Relayer
+    function lockEth(string calldata dstChain, string calldata dstAccount, bytes32 depositId, uint256 relayerTip)
         external
         payable
         nonReentrant
     {
         require(msg.value > 0, "Vault: zero amount");
+        require(relayerTip < msg.value, "Vault: tip too large");
+        require(relayerTip * 10_000 <= msg.value * maxRelayerTipBps, "Vault: tip above cap");
         require(!processed[depositId], "Vault: duplicate");
         processed[depositId] = true;
-        emit EthLocked(msg.sender, msg.value, depositId, dstChain, dstAccount);
+        emit EthLocked(msg.sender, msg.value, depositId, dstChain, dstAccount, relayerTip);
     }
 
     /// @dev Relayer 根据 Casper 侧 ceETH burn 事件释放 ETH
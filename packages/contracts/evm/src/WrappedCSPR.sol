@@ -12,7 +12,9 @@ contract WrappedCSPR is ERC20, AccessControl {
     /// 防重放：记录源链 txId（或 burn 事件 id）是否已处理
     mapping(bytes32 => bool) public processed;
 
-    event Minted(address indexed to, uint256 amount, bytes32 indexed srcTx);
+    event Minted(
+        address indexed to, uint256 amount, address indexed relayer, uint256 relayerTip, bytes32 indexed srcTx
+    );
     event Burned(address indexed from, uint256 amount, bytes32 indexed dstTx);
 
     constructor(address admin_) ERC20("Wrapped CSPR", "wCSPR") {
@@ -21,11 +23,15 @@ contract WrappedCSPR is ERC20, AccessControl {
     }
 
     /// @dev 仅拥有 MINTER_ROLE 的桥/Relayer 可调用，需传入源链 txId 防重放
-    function mint(address to, uint256 amount, bytes32 srcTx) external onlyRole(MINTER_ROLE) {
+    /// amount 与 relayerTip 取自 Casper 侧锁仓事件，小费在到账金额之外铸造给投递的 Relayer
+    function mint(address to, uint256 amount, uint256 relayerTip, bytes32 srcTx) external onlyRole(MINTER_ROLE) {
         require(!processed[srcTx], "wCSPR: processed");
         processed[srcTx] = true;
         _mint(to, amount);
-        emit Minted(to, amount, srcTx);
+        if (relayerTip > 0) {
+            _mint(msg.sender, relayerTip);
+        }
+        emit Minted(to, amount, msg.sender, relayerTip, srcTx);
     }
 
     /// @dev 用户自助 burn，dstTx 由前端/Relayer 生成并在桥侧防重放